@@ -0,0 +1,189 @@
+use crate::point::{Bounds, Point};
+use crate::point3::Point3;
+use crate::range::Range;
+
+//axis aligned box, one half open range per axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AxisBox<const D: usize> {
+  pub axes: [Range; D],
+}
+
+pub type Rect = AxisBox<2>;
+pub type Cuboid = AxisBox<3>;
+
+#[allow(unused)]
+impl<const D: usize> AxisBox<D> {
+  pub const fn new(axes: [Range; D]) -> Self {
+    Self { axes }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.axes.iter().any(|r| r.is_empty())
+  }
+
+  //area for rectangles, volume for cuboids
+  pub fn volume(&self) -> u128 {
+    self.axes.iter().map(|r| r.len as u128).product()
+  }
+
+  pub fn contains(&self, coords: [i64; D]) -> bool {
+    self.axes.iter().zip(coords).all(|(r, c)| r.contains(c))
+  }
+
+  pub fn intersection(&self, other: &Self) -> Option<Self> {
+    let mut axes = self.axes;
+    for (axis, o) in axes.iter_mut().zip(other.axes.iter()) {
+      *axis = axis.intersection(o)?;
+    }
+    Some(Self { axes })
+  }
+
+  pub fn overlaps(&self, other: &Self) -> bool {
+    self.intersection(other).is_some()
+  }
+
+  //disjoint boxes covering self minus other, at most 2 * D of them
+  pub fn subtract(&self, other: &Self) -> Vec<Self> {
+    let Some(common) = self.intersection(other) else {
+      return if self.is_empty() { vec![] } else { vec![*self] };
+    };
+    let mut pieces = vec![];
+    let mut remaining = *self;
+    for i in 0..D {
+      for part in remaining.axes[i].subtract(&common.axes[i]) {
+        let mut piece = remaining;
+        piece.axes[i] = part;
+        pieces.push(piece);
+      }
+      remaining.axes[i] = common.axes[i];
+    }
+    pieces
+  }
+}
+
+#[allow(unused)]
+impl Rect {
+  //inclusive bounds become half open ranges
+  pub fn from_bounds(bounds: &Bounds) -> Self {
+    let (min, max) = (bounds.min(), bounds.max());
+    Self::new([
+      Range::from_bounds(min.x, max.x + 1),
+      Range::from_bounds(min.y, max.y + 1),
+    ])
+  }
+
+  pub fn contains_point(&self, point: &Point) -> bool {
+    self.contains([point.x, point.y])
+  }
+}
+
+#[allow(unused)]
+impl Cuboid {
+  //both corners included, as in "1,0,1~1,2,1" brick descriptions
+  pub fn from_corners(a: Point3, b: Point3) -> Self {
+    let axis = |a: i64, b: i64| Range::from_bounds(a.min(b), a.max(b) + 1);
+    Self::new([axis(a.x, b.x), axis(a.y, b.y), axis(a.z, b.z)])
+  }
+
+  pub fn contains_point(&self, point: &Point3) -> bool {
+    self.contains([point.x, point.y, point.z])
+  }
+}
+
+//union of boxes kept as disjoint pieces, so its volume is a plain sum
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoxUnion<const D: usize> {
+  pieces: Vec<AxisBox<D>>,
+}
+
+impl<const D: usize> Default for BoxUnion<D> {
+  fn default() -> Self {
+    Self { pieces: vec![] }
+  }
+}
+
+#[allow(unused)]
+impl<const D: usize> BoxUnion<D> {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn pieces(&self) -> &[AxisBox<D>] {
+    &self.pieces
+  }
+
+  pub fn insert(&mut self, new: AxisBox<D>) {
+    let mut fresh = vec![new];
+    for piece in self.pieces.iter() {
+      fresh = fresh.iter().flat_map(|f| f.subtract(piece)).collect();
+      if fresh.is_empty() {
+        return;
+      }
+    }
+    self.pieces.extend(fresh);
+  }
+
+  pub fn remove(&mut self, old: &AxisBox<D>) {
+    self.pieces = self.pieces.iter().flat_map(|p| p.subtract(old)).collect();
+  }
+
+  pub fn contains(&self, coords: [i64; D]) -> bool {
+    self.pieces.iter().any(|p| p.contains(coords))
+  }
+
+  pub fn volume(&self) -> u128 {
+    self.pieces.iter().map(|p| p.volume()).sum()
+  }
+}
+
+impl<const D: usize> FromIterator<AxisBox<D>> for BoxUnion<D> {
+  fn from_iter<I: IntoIterator<Item = AxisBox<D>>>(iter: I) -> Self {
+    let mut union = Self::new();
+    for b in iter {
+      union.insert(b);
+    }
+    union
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn rect(x: std::ops::Range<i64>, y: std::ops::Range<i64>) -> Rect {
+    Rect::new([x.into(), y.into()])
+  }
+
+  #[test]
+  fn rectangles() {
+    let a = rect(0..4, 0..4);
+    let b = rect(2..6, 1..3);
+    assert_eq!(a.intersection(&b), Some(rect(2..4, 1..3)));
+    let pieces = a.subtract(&b);
+    assert_eq!(pieces.iter().map(|p| p.volume()).sum::<u128>(), 12);
+    assert!(pieces.iter().all(|p| !p.overlaps(&b)));
+    assert_eq!(a.subtract(&rect(10..12, 0..1)), vec![a]);
+    let bounds = Bounds::from_dims(3, 2);
+    assert_eq!(Rect::from_bounds(&bounds), rect(0..3, 0..2));
+    assert!(a.contains_point(&Point::new(3, 0)));
+    assert!(!a.contains_point(&Point::new(4, 0)));
+  }
+
+  #[test]
+  fn union_volume() {
+    let cubes = [
+      Cuboid::from_corners(Point3::new(10, 10, 10), Point3::new(12, 12, 12)),
+      Cuboid::from_corners(Point3::new(11, 11, 11), Point3::new(13, 13, 13)),
+      Cuboid::from_corners(Point3::new(10, 10, 10), Point3::new(10, 10, 10)),
+    ];
+    let mut union: BoxUnion<3> = cubes.into_iter().collect();
+    assert_eq!(union.volume(), 27 + 19);
+    union.remove(&Cuboid::from_corners(
+      Point3::new(9, 9, 9),
+      Point3::new(11, 11, 11),
+    ));
+    assert_eq!(union.volume(), 46 - 8);
+    assert!(!union.contains([10, 10, 10]));
+    assert!(union.contains([13, 13, 13]));
+  }
+}
@@ -0,0 +1,202 @@
+use crate::point::{Bounds, Point};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+//set of points inside fixed bounds, one bit per cell in row-major order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointSet {
+  bounds: Bounds,
+  words: Vec<u64>,
+}
+
+#[allow(unused)]
+impl PointSet {
+  pub fn new(bounds: Bounds) -> Self {
+    let cells = bounds.area() as usize;
+    Self {
+      bounds,
+      words: vec![0; cells.div_ceil(WORD_BITS)],
+    }
+  }
+
+  pub fn bounds(&self) -> Bounds {
+    self.bounds
+  }
+
+  pub fn index(&self, point: &Point) -> Option<usize> {
+    if !self.bounds.check(point) {
+      return None;
+    }
+    let offset = *point - self.bounds.min();
+    Some((offset.y * self.bounds.width() + offset.x) as usize)
+  }
+
+  pub fn point(&self, index: usize) -> Point {
+    let width = self.bounds.width();
+    let index = index as i64;
+    self.bounds.min() + Point::new(index % width, index / width)
+  }
+
+  //true when the point was not in the set yet; points out of bounds are ignored
+  pub fn insert(&mut self, point: &Point) -> bool {
+    let Some(index) = self.index(point) else {
+      return false;
+    };
+    let (word, bit) = (index / WORD_BITS, 1 << (index % WORD_BITS));
+    let inserted = self.words[word] & bit == 0;
+    self.words[word] |= bit;
+    inserted
+  }
+
+  pub fn remove(&mut self, point: &Point) -> bool {
+    let Some(index) = self.index(point) else {
+      return false;
+    };
+    let (word, bit) = (index / WORD_BITS, 1 << (index % WORD_BITS));
+    let removed = self.words[word] & bit != 0;
+    self.words[word] &= !bit;
+    removed
+  }
+
+  pub fn contains(&self, point: &Point) -> bool {
+    self
+      .index(point)
+      .is_some_and(|index| self.words[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0)
+  }
+
+  pub fn clear(&mut self) {
+    self.words.fill(0);
+  }
+
+  pub fn len(&self) -> usize {
+    self.words.iter().map(|w| w.count_ones() as usize).sum()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.words.iter().all(|&w| w == 0)
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+    self.words.iter().enumerate().flat_map(move |(i, &word)| {
+      let mut word = word;
+      std::iter::from_fn(move || {
+        if word == 0 {
+          return None;
+        }
+        let bit = word.trailing_zeros() as usize;
+        word &= word - 1;
+        Some(self.point(i * WORD_BITS + bit))
+      })
+    })
+  }
+}
+
+static DIRECTIONS: [Point; 8] = [
+  Point::new(0, -1),
+  Point::new(1, -1),
+  Point::new(1, 0),
+  Point::new(1, 1),
+  Point::new(0, 1),
+  Point::new(-1, 1),
+  Point::new(-1, 0),
+  Point::new(-1, -1),
+];
+
+//set of (point, direction) pairs, e.g. to detect a walker going through a cell the same way twice
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectionSet {
+  cells: PointSet,
+  masks: Vec<u8>,
+}
+
+#[allow(unused)]
+impl DirectionSet {
+  pub fn new(bounds: Bounds) -> Self {
+    Self {
+      cells: PointSet::new(bounds),
+      masks: vec![0; bounds.area() as usize],
+    }
+  }
+
+  fn direction_bit(direction: &Point) -> u8 {
+    let normalized = direction.normalize();
+    let position = DIRECTIONS.iter().position(|d| d == &normalized);
+    1 << position.expect("direction must not be zero")
+  }
+
+  pub fn insert(&mut self, point: &Point, direction: &Point) -> bool {
+    let Some(index) = self.cells.index(point) else {
+      return false;
+    };
+    let bit = Self::direction_bit(direction);
+    self.cells.insert(point);
+    let inserted = self.masks[index] & bit == 0;
+    self.masks[index] |= bit;
+    inserted
+  }
+
+  pub fn contains(&self, point: &Point, direction: &Point) -> bool {
+    self
+      .cells
+      .index(point)
+      .is_some_and(|index| self.masks[index] & Self::direction_bit(direction) != 0)
+  }
+
+  //directions stored for a cell, as a bit mask following the compass from up clockwise
+  pub fn mask(&self, point: &Point) -> u8 {
+    self.cells.index(point).map_or(0, |index| self.masks[index])
+  }
+
+  //points with at least one direction stored
+  pub fn points(&self) -> &PointSet {
+    &self.cells
+  }
+
+  //only touches the cells that were actually visited
+  pub fn clear(&mut self) {
+    for point in self.cells.iter() {
+      let index = self.cells.index(&point).unwrap();
+      self.masks[index] = 0;
+    }
+    self.cells.clear();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn point_set() {
+    let bounds = Bounds::new(Point::new(-2, -2), Point::new(10, 10));
+    let mut set = PointSet::new(bounds);
+    assert!(set.insert(&Point::new(-2, -2)));
+    assert!(!set.insert(&Point::new(-2, -2)));
+    assert!(set.insert(&Point::new(10, 10)));
+    assert!(set.insert(&Point::new(3, 4)));
+    assert!(!set.insert(&Point::new(11, 0)));
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&Point::new(3, 4)));
+    assert!(set.remove(&Point::new(3, 4)));
+    assert!(!set.contains(&Point::new(3, 4)));
+    let points: Vec<Point> = set.iter().collect();
+    assert_eq!(points, vec![Point::new(-2, -2), Point::new(10, 10)]);
+    set.clear();
+    assert!(set.is_empty());
+  }
+
+  #[test]
+  fn direction_set() {
+    let mut set = DirectionSet::new(Bounds::from_dims(5, 5));
+    let p = Point::new(1, 1);
+    assert!(set.insert(&p, &Point::new(0, -1)));
+    assert!(set.insert(&p, &Point::new(2, 0)));
+    assert!(!set.insert(&p, &Point::new(1, 0)));
+    assert!(set.contains(&p, &Point::new(0, -1)));
+    assert!(!set.contains(&p, &Point::new(0, 1)));
+    assert_eq!(set.mask(&p), 0b101);
+    set.clear();
+    assert_eq!(set.mask(&p), 0);
+    assert!(set.points().is_empty());
+  }
+}
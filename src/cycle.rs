@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+//(mu, lambda): the states repeat every lambda steps once mu steps have been taken
+pub type Cycle = (usize, usize);
+
+//step returns None when the sequence ends, e.g. a guard leaving the map, and then there is no cycle
+
+#[allow(unused)]
+pub fn floyd<S: PartialEq + Clone>(
+  start: S,
+  mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+  let mut tortoise = step(&start)?;
+  let mut hare = step(&tortoise)?;
+  while tortoise != hare {
+    tortoise = step(&tortoise)?;
+    hare = step(&hare)?;
+    hare = step(&hare)?;
+  }
+
+  let mut mu = 0;
+  tortoise = start;
+  while tortoise != hare {
+    tortoise = step(&tortoise)?;
+    hare = step(&hare)?;
+    mu += 1;
+  }
+
+  let mut lambda = 1;
+  hare = step(&tortoise)?;
+  while tortoise != hare {
+    hare = step(&hare)?;
+    lambda += 1;
+  }
+  Some((mu, lambda))
+}
+
+#[allow(unused)]
+pub fn brent<S: PartialEq + Clone>(
+  start: S,
+  mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+  let (mut power, mut lambda) = (1, 1);
+  let mut tortoise = start.clone();
+  let mut hare = step(&start)?;
+  while tortoise != hare {
+    if power == lambda {
+      tortoise = hare.clone();
+      power *= 2;
+      lambda = 0;
+    }
+    hare = step(&hare)?;
+    lambda += 1;
+  }
+
+  let mut mu = 0;
+  tortoise = start.clone();
+  hare = start;
+  for _ in 0..lambda {
+    hare = step(&hare)?;
+  }
+  while tortoise != hare {
+    tortoise = step(&tortoise)?;
+    hare = step(&hare)?;
+    mu += 1;
+  }
+  Some((mu, lambda))
+}
+
+//remembers every state, so it takes exactly mu + lambda steps
+#[allow(unused)]
+pub fn hashed<S: Hash + Eq + Clone>(
+  start: S,
+  mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+  let mut seen = HashMap::new();
+  let mut state = start;
+  for i in 0.. {
+    if let Some(first) = seen.insert(state.clone(), i) {
+      return Some((first, i - first));
+    }
+    state = step(&state)?;
+  }
+  unreachable!()
+}
+
+//state after n steps, skipping whole cycles once one is found; None when the sequence ends before
+#[allow(unused)]
+pub fn state_at<S: Hash + Eq + Clone>(
+  start: S,
+  n: usize,
+  mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<S> {
+  let mut seen: HashMap<S, usize> = HashMap::new();
+  let mut history: Vec<S> = vec![];
+  let mut state = start;
+  for i in 0..n {
+    if let Some(&mu) = seen.get(&state) {
+      let lambda = i - mu;
+      return Some(history[mu + (n - mu) % lambda].clone());
+    }
+    seen.insert(state.clone(), i);
+    history.push(state.clone());
+    state = step(&state)?;
+  }
+  Some(state)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::point::{Bounds, Point};
+
+  //0, 1, 2, then 3..8 forever
+  fn sequence(x: &u32) -> Option<u32> {
+    Some(if *x == 7 { 3 } else { x + 1 })
+  }
+
+  #[test]
+  fn finders_agree() {
+    assert_eq!(floyd(0, sequence), Some((3, 5)));
+    assert_eq!(brent(0, sequence), Some((3, 5)));
+    assert_eq!(hashed(0, sequence), Some((3, 5)));
+    let pure = |x: &u32| Some(x * x % 11);
+    assert_eq!(floyd(2, pure), hashed(2, pure));
+    assert_eq!(brent(2, pure), hashed(2, pure));
+    let ending = |x: &u32| (*x < 10).then_some(x + 1);
+    assert_eq!(floyd(0, ending), None);
+    assert_eq!(brent(0, ending), None);
+    assert_eq!(hashed(0, ending), None);
+  }
+
+  //a guard walking forward and turning right in front of walls, as in 2024 day 6
+  #[test]
+  fn guard_loop() {
+    let bounds = Bounds::from_dims(5, 5);
+    let mut walls = vec![
+      Point::new(1, 0),
+      Point::new(4, 1),
+      Point::new(0, 3),
+      Point::new(3, 4),
+    ];
+    let guard = |walls: &[Point]| {
+      let walls = walls.to_vec();
+      move |&(position, direction): &(Point, Point)| {
+        let ahead = position + direction;
+        if !bounds.check(&ahead) {
+          None
+        } else if walls.contains(&ahead) {
+          Some((position, direction.rotate_cw()))
+        } else {
+          Some((ahead, direction))
+        }
+      }
+    };
+    let start = (Point::new(1, 4), Point::new(0, -1));
+    assert_eq!(brent(start, guard(&walls)), Some((1, 12)));
+    assert_eq!(floyd(start, guard(&walls)), Some((1, 12)));
+    assert_eq!(hashed(start, guard(&walls)), Some((1, 12)));
+    walls.remove(1);
+    assert_eq!(brent(start, guard(&walls)), None);
+  }
+
+  #[test]
+  fn extrapolation() {
+    for n in 0..40 {
+      let mut expected = 0;
+      for _ in 0..n {
+        expected = sequence(&expected).unwrap();
+      }
+      assert_eq!(state_at(0, n, sequence), Some(expected));
+    }
+    assert_eq!(
+      state_at(0, 1_000_000_000, sequence),
+      Some(3 + (1_000_000_000 - 3) % 5)
+    );
+    let ending = |x: &u32| (*x < 10).then_some(x + 1);
+    assert_eq!(state_at(0, 10, ending), Some(10));
+    assert_eq!(state_at(0, 11, ending), None);
+  }
+}
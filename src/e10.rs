@@ -2,7 +2,7 @@ use std::collections::HashSet;
 use std::fs::File;
 use std::io::{prelude::*, BufReader};
 
-use crate::point::{Bounds, Matrix2DNavigator, Point};
+use crate::point::{Bounds, EdgePolicy, Matrix2DNavigator, Point};
 
 type Input = Vec<Vec<i64>>;
 type Output1 = i64;
@@ -69,6 +69,7 @@ fn initial(input: Input) -> Output1 {
     bounds,
     current: Point::default(),
     direction: Direction::Right.to_point(),
+    edge: EdgePolicy::Stop,
   };
 
   let mut score: i64 = 0;
@@ -115,6 +116,7 @@ fn extra(input: Input) -> Output2 {
     bounds,
     current: Point::default(),
     direction: Direction::Right.to_point(),
+    edge: EdgePolicy::Stop,
   };
 
   let mut score: i64 = 0;
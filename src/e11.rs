@@ -3,6 +3,8 @@ use std::collections::{BinaryHeap, HashMap};
 use std::fs::File;
 use std::io::{prelude::*, BufReader};
 
+use crate::numbers::split_digits;
+
 type Input = Vec<i64>;
 type Output1 = i64;
 type Output2 = i64;
@@ -52,12 +54,8 @@ impl Task {
     if self.value == 0 {
       return Some(vec![1]);
     }
-    let text = self.value.to_string();
-    if text.len() % 2 == 0 {
+    if let Some((left_value, right_value)) = split_digits(self.value) {
       //split in two
-      let (left, right) = text.split_at(text.len() / 2);
-      let left_value = left.parse::<i64>().unwrap();
-      let right_value = right.parse::<i64>().unwrap();
       return Some(vec![left_value, right_value]);
     }
 
@@ -1,4 +1,5 @@
-use crate::point::{Bounds, Matrix2DNavigator, PathStatus, Point};
+use crate::grid::Grid;
+use crate::point::{Bounds, EdgePolicy, Matrix2DNavigator, Point};
 use std::fs::File;
 use std::io::{prelude::*, BufReader};
 
@@ -17,51 +18,20 @@ fn read_data(is_test: bool) -> Input {
   line_iter.map(|l| l.unwrap().chars().collect()).collect()
 }
 
-static DIRECTIONS: [Point; 8] = [
-  Point::new(0, 1),
-  Point::new(1, 0),
-  Point::new(0, -1),
-  Point::new(-1, 0),
-  Point::new(1, 1),
-  Point::new(-1, -1),
-  Point::new(1, -1),
-  Point::new(-1, 1),
-];
-
 const TARGET: &str = "XMAS";
+const TARGET_REVERSED: &str = "SAMX";
 
 fn initial(input: Input) -> Output1 {
-  let mut score: Output1 = 0;
-  let bounds = Bounds::from_dims(input.len(), input[0].len());
-  for (i, line) in input.iter().enumerate() {
-    for (j, &c) in line.iter().enumerate() {
-      if c != 'X' {
-        continue;
-      }
-      let current = Point::new(i as i64, j as i64);
-      for &direction in DIRECTIONS.iter() {
-        let mut cursor = Matrix2DNavigator {
-          bounds,
-          current,
-          direction,
-        };
-
-        let path = cursor.get_path(4);
-        if path.status != PathStatus::Full {
-          continue;
-        }
-        let chars = path
-          .points
-          .iter()
-          .map(|p| input[p.x as usize][p.y as usize]);
-        let string: String = chars.collect();
-        if string.eq(TARGET) {
-          score += 1;
-        }
-      }
-    }
-  }
-  score
+  let grid = Grid::new(input);
+  let lines = grid
+    .rows()
+    .map(|r| r.iter().collect::<String>())
+    .chain(grid.columns().map(|c| c.collect()))
+    .chain(grid.diagonals().map(|d| d.collect()))
+    .chain(grid.anti_diagonals().map(|d| d.collect()));
+  lines
+    .map(|l| (l.matches(TARGET).count() + l.matches(TARGET_REVERSED).count()) as i64)
+    .sum()
 }
 
 fn extra(input: Input) -> Output2 {
@@ -89,6 +59,7 @@ fn extra(input: Input) -> Output2 {
         bounds,
         current: current + direction.opposite(),
         direction,
+        edge: EdgePolicy::Stop,
       };
       let path = cursor.get_path(3);
       let chars = path
@@ -106,6 +77,7 @@ fn extra(input: Input) -> Output2 {
         bounds,
         current: current + direction.opposite(),
         direction,
+        edge: EdgePolicy::Stop,
       };
       let path = cursor.get_path(3);
       let chars = path
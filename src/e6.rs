@@ -1,5 +1,5 @@
+use crate::bitset::{DirectionSet, PointSet};
 use crate::point::{Bounds, Point};
-use std::collections::HashSet;
 use std::fs::File;
 use std::io::{prelude::*, BufReader};
 
@@ -119,16 +119,16 @@ impl Game {
 }
 
 fn initial(input: Input) -> Output1 {
-  let mut visited: HashSet<Point> = HashSet::new();
   let game = Game::new(input);
   let mut cursor = GameCursor {
     position: game.guard_position(),
     bounds: Bounds::from_dims(game.map.len(), game.map[0].len()),
     direction: Direction::Up,
   };
+  let mut visited = PointSet::new(cursor.bounds);
 
   loop {
-    visited.insert(cursor.position);
+    visited.insert(&cursor.position);
     let next = cursor.next();
     if next.is_none() {
       //out of bounds
@@ -147,17 +147,18 @@ fn initial(input: Input) -> Output1 {
 }
 
 fn extra(input: Input) -> Output2 {
-  let mut new_blocks: HashSet<Point> = HashSet::new();
-  let mut visited: HashSet<Point> = HashSet::new();
   let game = Game::new(input);
   let mut cursor = GameCursor {
     position: game.guard_position(),
     bounds: Bounds::from_dims(game.map.len(), game.map[0].len()),
     direction: Direction::Up,
   };
+  let mut new_blocks = PointSet::new(cursor.bounds);
+  let mut visited = PointSet::new(cursor.bounds);
+  let mut corner_tracker = DirectionSet::new(cursor.bounds);
 
   loop {
-    visited.insert(cursor.position);
+    visited.insert(&cursor.position);
     let Some(next) = cursor.next() else {
       //out of bounds
       break;
@@ -168,14 +169,18 @@ fn extra(input: Input) -> Output2 {
     } else if let Cell::Empty = next_cell {
       if !visited.contains(&next) {
         //try to set a new block and see what happens
-        let mut corner_tracker: HashSet<GameCursor> = HashSet::new();
+        corner_tracker.clear();
         let mut explore_cursor = cursor.clone();
-        corner_tracker.insert(explore_cursor.clone());
+        corner_tracker.insert(
+          &explore_cursor.position,
+          &explore_cursor.direction.to_point(),
+        );
         explore_cursor.rotate();
 
         loop {
-          if corner_tracker.contains(&explore_cursor) {
-            new_blocks.insert(next);
+          let explore_direction = explore_cursor.direction.to_point();
+          if corner_tracker.contains(&explore_cursor.position, &explore_direction) {
+            new_blocks.insert(&next);
             break;
           }
 
@@ -190,7 +195,7 @@ fn extra(input: Input) -> Output2 {
             explore_next_cell
           };
           if &Cell::Wall == corrected_next_cell {
-            corner_tracker.insert(explore_cursor.clone());
+            corner_tracker.insert(&explore_cursor.position, &explore_direction);
             explore_cursor.rotate();
           } else {
             explore_cursor.advance();
@@ -4,6 +4,8 @@ use std::io::{prelude::*, BufReader};
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::numbers::concat;
+
 type Input = Vec<String>;
 type Output1 = i64;
 type Output2 = i64;
@@ -34,126 +36,23 @@ impl Request {
     Self { result, values }
   }
 
-  fn is_valid(&self) -> bool {
-    let l = self.values.len();
-    let l_ops = l - 1;
-    let mut operations: Vec<Operation> = vec![];
-    let mut accs = vec![self.values[0]];
-
-    loop {
-      let mut depth = operations.len();
-      if depth < l_ops {
-        //go deeper
-        let op = OperationCursor::first();
-        operations.push(op);
-        let last_acc = accs.last().unwrap();
-        let next_value = self.values[depth + 1];
-        let next_acc = op.apply(*last_acc, next_value);
-        accs.push(next_acc);
-        continue;
-      }
-
-      let last_acc = accs.last().unwrap();
-      if last_acc == &self.result {
-        return true;
-      }
-
-      //go up
-      loop {
-        let op = operations.pop().unwrap();
-        accs.pop();
-        depth -= 1;
-        let Some(next_op) = OperationCursor::next(&op) else {
-          if depth == 0 {
-            return false;
-          }
-          continue;
-        };
-        operations.push(next_op);
-        let next_value = self.values[depth + 1];
-        let last_acc = accs.last().unwrap();
-        let next_acc = next_op.apply(*last_acc, next_value);
-        accs.push(next_acc);
-        break;
-      }
-    }
-  }
-
-  fn is_valid_v2(&self) -> bool {
-    let l = self.values.len();
-    let l_ops = l - 1;
-    let mut operations: Vec<Operation> = vec![];
-    let mut accs = vec![self.values[0]];
-
-    loop {
-      let mut depth = operations.len();
-      if depth < l_ops {
-        //go deeper
-        let op = OperationCursorV2::first();
-        operations.push(op);
-        let last_acc = accs.last().unwrap();
-        let next_value = self.values[depth + 1];
-        let next_acc = op.apply(*last_acc, next_value);
-        accs.push(next_acc);
-        continue;
-      }
-
-      let last_acc = accs.last().unwrap();
-      if last_acc == &self.result {
-        return true;
-      }
-
-      //go up
-      loop {
-        let op = operations.pop().unwrap();
-        accs.pop();
-        depth -= 1;
-        let Some(next_op) = OperationCursorV2::next(&op) else {
-          if depth == 0 {
-            return false;
-          }
-          continue;
-        };
-        operations.push(next_op);
-        let next_value = self.values[depth + 1];
-        let last_acc = accs.last().unwrap();
-        let next_acc = next_op.apply(*last_acc, next_value);
-        accs.push(next_acc);
-        break;
-      }
-    }
-  }
-}
-
-struct OperationCursor {}
-
-impl OperationCursor {
-  const fn next(op: &Operation) -> Option<Operation> {
-    match op {
-      Operation::Add => Some(Operation::Mul),
-      Operation::Mul => None,
-      _ => unreachable!(),
-    }
-  }
-
-  const fn first() -> Operation {
-    Operation::Add
+  fn is_valid(&self, operations: &[Operation]) -> bool {
+    let (first, rest) = self.values.split_first().unwrap();
+    self.reaches(*first, rest, operations)
   }
-}
-
-struct OperationCursorV2 {}
 
-impl OperationCursorV2 {
-  const fn next(op: &Operation) -> Option<Operation> {
-    match op {
-      Operation::Add => Some(Operation::Mul),
-      Operation::Mul => Some(Operation::Concat),
-      Operation::Concat => None,
+  //depth first over the operators, so every prefix is computed once
+  fn reaches(&self, acc: i64, rest: &[i64], operations: &[Operation]) -> bool {
+    //values only grow unless a 0 is left to multiply by, so an overshooting prefix is dropped
+    if acc > self.result && !rest.contains(&0) {
+      return false;
     }
-  }
-
-  const fn first() -> Operation {
-    Operation::Add
+    let Some((&value, rest)) = rest.split_first() else {
+      return acc == self.result;
+    };
+    operations
+      .iter()
+      .any(|op| self.reaches(op.apply(acc, value), rest, operations))
   }
 }
 
@@ -169,11 +68,7 @@ impl Operation {
     match self {
       Operation::Add => a + b,
       Operation::Mul => a * b,
-      Operation::Concat => {
-        let a_str = a.to_string();
-        let b_str = b.to_string();
-        format!("{}{}", a_str, b_str).parse().unwrap()
-      }
+      Operation::Concat => concat(a, b).unwrap(),
     }
   }
 }
@@ -191,7 +86,7 @@ fn initial(input: Input) -> Output1 {
   input
     .into_iter()
     .map(|l| Request::from(&l))
-    .filter(Request::is_valid)
+    .filter(|r| r.is_valid(&[Operation::Add, Operation::Mul]))
     .map(|r| r.result)
     .sum()
 }
@@ -200,7 +95,7 @@ fn extra(input: Input) -> Output2 {
   input
     .into_iter()
     .map(|l| Request::from(&l))
-    .filter(Request::is_valid_v2)
+    .filter(|r| r.is_valid(&[Operation::Add, Operation::Mul, Operation::Concat]))
     .map(|r| r.result)
     .sum()
 }
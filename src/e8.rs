@@ -2,6 +2,7 @@ use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::{prelude::*, BufReader};
 
+use crate::numbers::combinations;
 use crate::point::{Bounds, Point};
 
 type Input = Vec<String>;
@@ -67,21 +68,17 @@ impl Game {
   fn solve(&self) -> i64 {
     let mut antenna_pos: HashSet<Point> = HashSet::new();
     for (_, points) in self.pos_map.iter() {
-      let n = points.len();
-      if n < 2 {
-        continue;
-      }
-      for (i, &a) in points.iter().enumerate().take(n - 1) {
-        for &b in points.iter().skip(i + 1) {
-          let diff = b - a;
-          let antenna = a - diff;
-          if self.bounds.check(&antenna) {
-            antenna_pos.insert(antenna);
-          }
-          let antenna = b + diff;
-          if self.bounds.check(&antenna) {
-            antenna_pos.insert(antenna);
-          }
+      let points: Vec<Point> = points.iter().copied().collect();
+      for pair in combinations(points.len(), 2) {
+        let (a, b) = (points[pair[0]], points[pair[1]]);
+        let diff = b - a;
+        let antenna = a - diff;
+        if self.bounds.check(&antenna) {
+          antenna_pos.insert(antenna);
+        }
+        let antenna = b + diff;
+        if self.bounds.check(&antenna) {
+          antenna_pos.insert(antenna);
         }
       }
     }
@@ -91,35 +88,15 @@ impl Game {
   fn solve_v2(&self) -> i64 {
     let mut antenna_pos: HashSet<Point> = HashSet::new();
     for (_, points) in self.pos_map.iter() {
-      let n = points.len();
-      if n < 2 {
-        continue;
-      }
-      for (i, &a) in points.iter().enumerate().take(n - 1) {
-        for &b in points.iter().skip(i + 1) {
-          let diff = b - a;
-          let mut start = b;
-          let step = -diff;
-          loop {
-            let next = start + step;
-            if !self.bounds.check(&next) {
-              break;
-            } else {
-              antenna_pos.insert(next);
-            }
-            start = next;
-          }
-
-          start = a;
-          let step = diff;
-          loop {
-            let next = start + step;
-            if !self.bounds.check(&next) {
-              break;
-            } else {
-              antenna_pos.insert(next);
-            }
-            start = next;
+      let points: Vec<Point> = points.iter().copied().collect();
+      for pair in combinations(points.len(), 2) {
+        let (a, b) = (points[pair[0]], points[pair[1]]);
+        let step = (b - a).reduced();
+        for direction in [step, -step] {
+          let mut next = a;
+          while self.bounds.check(&next) {
+            antenna_pos.insert(next);
+            next += direction;
           }
         }
       }
@@ -1,6 +1,6 @@
-use crate::numbers::n_natural_sum_range;
+use crate::numbers::weighted_range_sum;
 use crate::range::Range;
-use std::collections::BTreeMap;
+use crate::segment_tree::GapIndex;
 use std::fs::File;
 use std::io::{prelude::*, BufReader};
 
@@ -131,60 +131,38 @@ fn sort_blocks(blocks: &Vec<Block>) -> Vec<Block> {
   sorted
 }
 
-fn sort_blocks_v2(blocks: &Vec<Block>) -> Vec<Block> {
-  let mut bmap: BTreeMap<i64, Block> = BTreeMap::new();
-
-  blocks.iter().for_each(|&block| {
-    bmap.insert(block.range.start, block);
-  });
+fn sort_blocks_v2(blocks: &[Block]) -> Vec<Block> {
+  let gaps = blocks
+    .iter()
+    .filter(|b| b.block_type == BlockType::Empty)
+    .map(|b| b.range)
+    .collect();
+  let mut gap_index = GapIndex::new(gaps);
+  let mut files: Vec<Block> = vec![];
 
   for &block in blocks.iter().rev() {
-    let BlockType::Id(id) = block.block_type else {
-      continue;
-    };
-
-    let current_block_len = block.range.len;
-
-    //find a place to insert it in the map
-    let gap = bmap
-      .values()
-      .take_while(|b| {
-        if let BlockType::Id(gap_id) = b.block_type {
-          return gap_id != id;
-        }
-        true
-      })
-      .filter(|b| b.block_type == BlockType::Empty)
-      .find(|b| {
-        let b_len = b.range.len;
-        b_len >= current_block_len
-      });
-
-    let Some(gap) = gap else {
+    let BlockType::Id(_) = block.block_type else {
       continue;
     };
 
-    let gap_pos = gap.range.start;
-    let mut current_block = bmap.remove(&block.range.start).unwrap();
-
-    //remove the gap and insert the current block
-    let gap = bmap.remove(&gap_pos).unwrap();
-    current_block.range.start = gap_pos;
-    bmap.insert(gap_pos, current_block);
-    let diff = gap.range.len - current_block_len;
-    if diff > 0 {
-      let gap_pos = gap.range.start + current_block_len as i64;
-      bmap.insert(
-        gap_pos,
-        Block {
-          block_type: BlockType::Empty,
-          range: Range::new(gap_pos, diff as u64),
-        },
-      );
+    //find the leftmost gap on the left side big enough for the file
+    let mut current_block = block;
+    if let Some(slot) = gap_index.find(block.range.len, block.range.start) {
+      current_block.range = gap_index.take(slot, block.range.len);
     }
+    files.push(current_block);
   }
 
-  let sorted: Vec<Block> = bmap.into_values().collect();
+  let remaining_gaps = gap_index
+    .gaps()
+    .iter()
+    .filter(|g| !g.is_empty())
+    .map(|&range| Block {
+      block_type: BlockType::Empty,
+      range,
+    });
+  let mut sorted: Vec<Block> = files.into_iter().chain(remaining_gaps).collect();
+  sorted.sort_by_key(|b| b.range.start);
 
   sorted
 }
@@ -193,7 +171,7 @@ fn compute_score(blocks: &Vec<Block>) -> i64 {
   let mut score = 0;
   for block in blocks {
     if let BlockType::Id(id) = block.block_type {
-      score += id * n_natural_sum_range(block.range);
+      score += weighted_range_sum(id, block.range);
     }
   }
   score
@@ -0,0 +1,217 @@
+use crate::point::{Bounds, Point};
+
+//rectangular grid stored row by row, indexed with x as column and y as row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+  rows: Vec<Vec<T>>,
+}
+
+#[allow(unused)]
+impl<T> Grid<T> {
+  pub fn new(rows: Vec<Vec<T>>) -> Self {
+    debug_assert!(rows.windows(2).all(|w| w[0].len() == w[1].len()));
+    Self { rows }
+  }
+
+  pub fn into_rows(self) -> Vec<Vec<T>> {
+    self.rows
+  }
+
+  pub fn width(&self) -> usize {
+    self.rows.first().map_or(0, |r| r.len())
+  }
+
+  pub fn height(&self) -> usize {
+    self.rows.len()
+  }
+
+  pub fn bounds(&self) -> Bounds {
+    Bounds::from_dims(self.width(), self.height())
+  }
+
+  pub fn get(&self, point: &Point) -> Option<&T> {
+    if !self.bounds().check(point) {
+      return None;
+    }
+    Some(&self.rows[point.y as usize][point.x as usize])
+  }
+
+  pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+    if !self.bounds().check(point) {
+      return None;
+    }
+    Some(&mut self.rows[point.y as usize][point.x as usize])
+  }
+
+  pub fn points(&self) -> impl Iterator<Item = (Point, &T)> {
+    self
+      .bounds()
+      .iter()
+      .map(|p| (p, &self.rows[p.y as usize][p.x as usize]))
+  }
+
+  pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+    self.points().find(|(_, v)| predicate(v)).map(|(p, _)| p)
+  }
+
+  //cells from start, moving by direction until leaving the grid
+  pub fn walk(&self, start: Point, direction: Point) -> impl Iterator<Item = &T> {
+    let bounds = self.bounds();
+    std::iter::successors(Some(start), move |&p| Some(p + direction))
+      .take_while(move |p| bounds.check(p))
+      .map(|p| &self.rows[p.y as usize][p.x as usize])
+  }
+
+  pub fn row(&self, y: usize) -> &[T] {
+    &self.rows[y]
+  }
+
+  pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+    self.rows.iter().map(|r| r.as_slice())
+  }
+
+  pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+    self.rows.iter().map(move |r| &r[x])
+  }
+
+  pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+    (0..self.width()).map(|x| self.column(x))
+  }
+
+  //lines going down and to the right, starting from the bottom left corner
+  pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+    let (w, h) = (self.width() as i64, self.height() as i64);
+    ((1 - h)..w).map(move |d| self.walk(Point::new(d.max(0), (-d).max(0)), Point::new(1, 1)))
+  }
+
+  //lines going down and to the left, starting from the top left corner
+  pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+    let (w, h) = (self.width() as i64, self.height() as i64);
+    (0..(w + h - 1)).map(move |s| {
+      let x = s.min(w - 1);
+      self.walk(Point::new(x, s - x), Point::new(-1, 1))
+    })
+  }
+
+  //rows of the part of the grid inside bounds, borrowed from the grid
+  pub fn window(&self, bounds: &Bounds) -> impl Iterator<Item = &[T]> {
+    let window = bounds.intersection(&self.bounds());
+    let (min, max) = window.map_or((Point::new(0, 0), Point::new(-1, -1)), |b| {
+      (b.min(), b.max())
+    });
+    self.rows[min.y as usize..(max.y + 1) as usize]
+      .iter()
+      .map(move |r| &r[min.x as usize..(max.x + 1) as usize])
+  }
+
+  pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+    Grid::new(
+      self
+        .rows
+        .iter()
+        .map(|r| r.iter().map(&f).collect())
+        .collect(),
+    )
+  }
+}
+
+#[allow(unused)]
+impl<T: Clone> Grid<T> {
+  pub fn filled(width: usize, height: usize, value: T) -> Self {
+    Self::new(vec![vec![value; width]; height])
+  }
+
+  pub fn sub_grid(&self, bounds: &Bounds) -> Self {
+    Self::new(self.window(bounds).map(|r| r.to_vec()).collect())
+  }
+
+  pub fn transpose(&self) -> Self {
+    Self::new(self.columns().map(|c| c.cloned().collect()).collect())
+  }
+
+  pub fn flip_horizontal(&self) -> Self {
+    Self::new(
+      self
+        .rows
+        .iter()
+        .map(|r| r.iter().rev().cloned().collect())
+        .collect(),
+    )
+  }
+
+  pub fn flip_vertical(&self) -> Self {
+    Self::new(self.rows.iter().rev().cloned().collect())
+  }
+
+  pub fn rotate_cw(&self) -> Self {
+    self.transpose().flip_horizontal()
+  }
+
+  pub fn rotate_ccw(&self) -> Self {
+    self.transpose().flip_vertical()
+  }
+
+  pub fn rotate_180(&self) -> Self {
+    self.flip_horizontal().flip_vertical()
+  }
+}
+
+#[allow(unused)]
+impl Grid<char> {
+  pub fn from_lines<S: AsRef<str>>(lines: &[S]) -> Self {
+    Self::new(lines.iter().map(|l| l.as_ref().chars().collect()).collect())
+  }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+  fn from(rows: Vec<Vec<T>>) -> Self {
+    Self::new(rows)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample() -> Grid<char> {
+    Grid::from_lines(&["abc", "def"])
+  }
+
+  fn to_strings(grid: &Grid<char>) -> Vec<String> {
+    grid.rows().map(|r| r.iter().collect()).collect()
+  }
+
+  #[test]
+  fn transformations() {
+    let grid = sample();
+    assert_eq!(to_strings(&grid.transpose()), ["ad", "be", "cf"]);
+    assert_eq!(to_strings(&grid.rotate_cw()), ["da", "eb", "fc"]);
+    assert_eq!(to_strings(&grid.rotate_ccw()), ["cf", "be", "ad"]);
+    assert_eq!(to_strings(&grid.rotate_180()), ["fed", "cba"]);
+    assert_eq!(to_strings(&grid.flip_horizontal()), ["cba", "fed"]);
+    assert_eq!(to_strings(&grid.flip_vertical()), ["def", "abc"]);
+    assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+  }
+
+  #[test]
+  fn lines() {
+    let grid = sample();
+    let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+    assert_eq!(columns, ["ad", "be", "cf"]);
+    let diagonals: Vec<String> = grid.diagonals().map(|c| c.collect()).collect();
+    assert_eq!(diagonals, ["d", "ae", "bf", "c"]);
+    let anti: Vec<String> = grid.anti_diagonals().map(|c| c.collect()).collect();
+    assert_eq!(anti, ["a", "bd", "ce", "f"]);
+  }
+
+  #[test]
+  fn windows() {
+    let grid = sample();
+    let bounds = Bounds::new(Point::new(1, 0), Point::new(5, 0));
+    assert_eq!(to_strings(&grid.sub_grid(&bounds)), ["bc"]);
+    let outside = Bounds::new(Point::new(5, 5), Point::new(6, 6));
+    assert_eq!(grid.window(&outside).count(), 0);
+    assert_eq!(grid.get(&Point::new(2, 1)), Some(&'f'));
+    assert_eq!(grid.get(&Point::new(3, 1)), None);
+  }
+}
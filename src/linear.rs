@@ -0,0 +1,169 @@
+use num::{BigInt, BigRational, Signed, ToPrimitive, Zero};
+
+#[allow(unused)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solution {
+  Unique(Vec<BigRational>),
+  None,
+  Infinite,
+}
+
+#[allow(unused)]
+impl Solution {
+  pub fn is_integral(&self) -> bool {
+    match self {
+      Solution::Unique(values) => values.iter().all(|v| v.is_integer()),
+      _ => false,
+    }
+  }
+
+  //the unique solution when all its values are non negative integers fitting an i64
+  pub fn non_negative_integers(&self) -> Option<Vec<i64>> {
+    let Solution::Unique(values) = self else {
+      return None;
+    };
+    values
+      .iter()
+      .map(|v| {
+        if v.is_integer() && !v.is_negative() {
+          v.to_integer().to_i64()
+        } else {
+          None
+        }
+      })
+      .collect()
+  }
+}
+
+#[allow(unused)]
+fn rational(value: i64) -> BigRational {
+  BigRational::from_integer(BigInt::from(value))
+}
+
+//a * x = rhs with Cramer's rule, e.g. how many presses of two buttons reach a prize
+#[allow(unused)]
+pub fn solve_2x2(a: [[i64; 2]; 2], rhs: [i64; 2]) -> Solution {
+  let [[a, b], [c, d]] = a.map(|row| row.map(BigInt::from));
+  let [e, f] = rhs.map(BigInt::from);
+  let det = &a * &d - &b * &c;
+  if !det.is_zero() {
+    let x = BigRational::new(&e * &d - &b * &f, det.clone());
+    let y = BigRational::new(&a * &f - &e * &c, det);
+    return Solution::Unique(vec![x, y]);
+  }
+  let no_coefficients = [&a, &b, &c, &d].iter().all(|v| v.is_zero());
+  let consistent = if no_coefficients {
+    e.is_zero() && f.is_zero()
+  } else {
+    //rows are proportional, so rhs must follow the same ratio
+    (&a * &f - &c * &e).is_zero() && (&b * &f - &d * &e).is_zero()
+  };
+  if consistent {
+    Solution::Infinite
+  } else {
+    Solution::None
+  }
+}
+
+//gauss-jordan elimination over one row per equation, any number of unknowns
+#[allow(unused)]
+pub fn solve(matrix: &[Vec<i64>], rhs: &[i64]) -> Solution {
+  assert_eq!(matrix.len(), rhs.len(), "one rhs value per equation");
+  let unknowns = matrix.first().map_or(0, |row| row.len());
+  let mut rows: Vec<Vec<BigRational>> = matrix
+    .iter()
+    .zip(rhs)
+    .map(|(row, &r)| {
+      assert_eq!(row.len(), unknowns, "every equation needs all unknowns");
+      row.iter().chain([&r]).map(|&v| rational(v)).collect()
+    })
+    .collect();
+
+  let mut rank = 0;
+  for column in 0..unknowns {
+    let Some(pivot) = (rank..rows.len()).find(|&r| !rows[r][column].is_zero()) else {
+      continue;
+    };
+    rows.swap(rank, pivot);
+    let scale = rows[rank][column].clone();
+    for v in rows[rank].iter_mut() {
+      *v /= &scale;
+    }
+    let pivot_row = rows[rank].clone();
+    for (r, row) in rows.iter_mut().enumerate() {
+      if r == rank || row[column].is_zero() {
+        continue;
+      }
+      let factor = row[column].clone();
+      for (v, p) in row.iter_mut().zip(pivot_row.iter()) {
+        *v -= &factor * p;
+      }
+    }
+    rank += 1;
+  }
+
+  //0 = non zero left over below the pivots
+  if rows[rank..].iter().any(|row| !row[unknowns].is_zero()) {
+    return Solution::None;
+  }
+  if rank < unknowns {
+    return Solution::Infinite;
+  }
+  Solution::Unique(
+    rows
+      .into_iter()
+      .take(unknowns)
+      .map(|row| row[unknowns].clone())
+      .collect(),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn claw_machines() {
+    let prize = solve_2x2([[94, 22], [34, 67]], [8400, 5400]);
+    assert_eq!(prize.non_negative_integers(), Some(vec![80, 40]));
+    let unreachable = solve_2x2([[26, 67], [66, 21]], [12748, 12176]);
+    assert!(matches!(unreachable, Solution::Unique(_)));
+    assert!(!unreachable.is_integral());
+    assert_eq!(unreachable.non_negative_integers(), None);
+    let far = 10000000000000;
+    let shifted = solve_2x2([[26, 67], [66, 21]], [12748 + far, 12176 + far]);
+    assert_eq!(
+      shifted.non_negative_integers(),
+      Some(vec![118679050709, 103199174542])
+    );
+    assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 6]), Solution::Infinite);
+    assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 7]), Solution::None);
+    assert_eq!(solve_2x2([[0, 0], [0, 0]], [0, 1]), Solution::None);
+  }
+
+  #[test]
+  fn elimination() {
+    let matrix = vec![vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+    let solution = solve(&matrix, &[8, -11, -3]);
+    assert_eq!(solution.non_negative_integers(), None);
+    assert_eq!(
+      solution,
+      Solution::Unique(vec![rational(2), rational(3), rational(-1)])
+    );
+    let half = solve(&[vec![2, 0], vec![0, 4]], &[1, 8]);
+    assert_eq!(
+      half,
+      Solution::Unique(vec![BigRational::new(1.into(), 2.into()), rational(2)])
+    );
+    assert!(!half.is_integral());
+    assert_eq!(
+      solve(&[vec![1, 1], vec![2, 2], vec![1, -1]], &[4, 8, 0]).non_negative_integers(),
+      Some(vec![2, 2])
+    );
+    assert_eq!(
+      solve(&[vec![1, 1], vec![2, 2]], &[4, 8]),
+      Solution::Infinite
+    );
+    assert_eq!(solve(&[vec![1, 1], vec![2, 2]], &[4, 9]), Solution::None);
+  }
+}
@@ -11,9 +11,24 @@ mod e7;
 mod e8;
 mod e9;
 
+mod axis_box;
+mod bitset;
+mod cycle;
+mod grid;
+mod linear;
 mod numbers;
+mod parse;
 mod point;
+mod point3;
+mod point_n;
+mod polygon;
 mod range;
+mod range_map;
+mod range_set;
+mod raycast;
+mod render;
+mod segment_tree;
+mod sparse_grid;
 
 use std::env;
 use std::time::Instant;
@@ -1,17 +1,492 @@
+use num::{Integer, PrimInt, Signed};
+
 use crate::range::Range;
 
+#[allow(unused)]
 pub fn n_natural_sum(n: i64) -> i64 {
   n * (n + 1) / 2
 }
 
+#[allow(unused)]
 pub fn n_natural_sum_between(start: i64, end: i64) -> i64 {
   n_natural_sum(end) - n_natural_sum(start - 1)
 }
 
+#[allow(unused)]
 pub fn n_natural_sum_range(range: Range) -> i64 {
   n_natural_sum_between(range.start, range.end() - 1)
 }
 
+//series are evaluated in i128; the checked variants return None when the result does not fit
+//an i64 or the number of terms is negative
+
+//first + (first + step) + ... with n terms
+pub fn checked_arithmetic_sum(first: i64, step: i64, n: i64) -> Option<i64> {
+  if n < 0 {
+    return None;
+  }
+  let (first, step, n) = (first as i128, step as i128, n as i128);
+  let steps = step.checked_mul(n * (n - 1) / 2)?;
+  (n * first).checked_add(steps)?.try_into().ok()
+}
+
+#[allow(unused)]
+pub fn arithmetic_sum(first: i64, step: i64, n: i64) -> i64 {
+  checked_arithmetic_sum(first, step, n).expect("arithmetic sum overflow")
+}
+
+//first + first * ratio + ... with n terms
+pub fn checked_geometric_sum(first: i64, ratio: i64, n: u32) -> Option<i64> {
+  let (first, ratio) = (first as i128, ratio as i128);
+  let sum = if ratio == 1 {
+    first.checked_mul(n as i128)?
+  } else {
+    first.checked_mul((ratio.checked_pow(n)? - 1) / (ratio - 1))?
+  };
+  sum.try_into().ok()
+}
+
+#[allow(unused)]
+pub fn geometric_sum(first: i64, ratio: i64, n: u32) -> i64 {
+  checked_geometric_sum(first, ratio, n).expect("geometric sum overflow")
+}
+
+//1^2 + 2^2 + ... + n^2
+pub fn checked_sum_of_squares(n: i64) -> Option<i64> {
+  if n < 0 {
+    return None;
+  }
+  let n = n as i128;
+  ((n * (n + 1)).checked_mul(2 * n + 1)? / 6).try_into().ok()
+}
+
+#[allow(unused)]
+pub fn sum_of_squares(n: i64) -> i64 {
+  checked_sum_of_squares(n).expect("sum of squares overflow")
+}
+
+//start + (start + step) + ... for every value below end, like (start..end).step_by(step);
+//None for a non positive step too
+pub fn checked_stepped_sum(start: i64, end: i64, step: i64) -> Option<i64> {
+  if step <= 0 {
+    return None;
+  }
+  let n = if end > start {
+    ((end as i128 - start as i128 + step as i128 - 1) / step as i128) as i64
+  } else {
+    0
+  };
+  checked_arithmetic_sum(start, step, n)
+}
+
+#[allow(unused)]
+pub fn stepped_sum(start: i64, end: i64, step: i64) -> i64 {
+  checked_stepped_sum(start, end, step).expect("stepped sum overflow or non positive step")
+}
+
+//weight * (start + ... + end - 1), e.g. a file id times the positions it covers
+pub fn checked_weighted_range_sum(weight: i64, range: Range) -> Option<i64> {
+  let sum = checked_arithmetic_sum(range.start, 1, range.len as i64)?;
+  weight.checked_mul(sum)
+}
+
+pub fn weighted_range_sum(weight: i64, range: Range) -> i64 {
+  checked_weighted_range_sum(weight, range).expect("weighted sum overflow")
+}
+
+#[allow(unused)]
+pub fn gcd_all<T: Integer + Copy>(values: &[T]) -> T {
+  values.iter().fold(T::zero(), |acc, v| acc.gcd(v))
+}
+
+#[allow(unused)]
+pub fn lcm_all<T: Integer + Copy>(values: &[T]) -> T {
+  values.iter().fold(T::one(), |acc, v| acc.lcm(v))
+}
+
+//(g, x, y) such that a * x + b * y = g = gcd(a, b)
+#[allow(unused)]
+pub fn extended_gcd<T: Integer + Signed + Copy>(a: T, b: T) -> (T, T, T) {
+  let e = a.extended_gcd(&b);
+  (e.gcd, e.x, e.y)
+}
+
+//x in [0, modulus) with a * x = 1 (mod modulus), when a and modulus are coprime
+//and modulus is positive
+#[allow(unused)]
+pub fn mod_inverse<T: Integer + Signed + Copy>(a: T, modulus: T) -> Option<T> {
+  if !modulus.is_positive() {
+    return None;
+  }
+  let (g, x, _) = extended_gcd(a.mod_floor(&modulus), modulus);
+  if g.is_one() {
+    Some(x.mod_floor(&modulus))
+  } else {
+    None
+  }
+}
+
+//operands are reduced below the modulus and multiplied as u128, so any modulus up to
+//u64::MAX works; panics for a non positive modulus
+#[allow(unused)]
+pub fn mod_pow<T: PrimInt>(base: T, exp: u64, modulus: T) -> T {
+  let modulus = modulus
+    .to_u64()
+    .filter(|&m| m > 0)
+    .expect("modulus must be in 1..=u64::MAX") as u128;
+  let mut base = base.to_i128().unwrap().rem_euclid(modulus as i128) as u128;
+  let mut exp = exp;
+  let mut result = 1 % modulus;
+  while exp > 0 {
+    if exp & 1 == 1 {
+      result = result * base % modulus;
+    }
+    base = base * base % modulus;
+    exp >>= 1;
+  }
+  T::from(result).unwrap()
+}
+
+//chinese remainder theorem for (remainder, modulus) pairs, moduli need not be coprime;
+//returns (x, lcm of the moduli) with x in [0, lcm), or None when the system has no solution,
+//a modulus is not positive or the lcm does not fit
+#[allow(unused)]
+pub fn crt<T: PrimInt>(congruences: &[(T, T)]) -> Option<(T, T)> {
+  let mut x: i128 = 0;
+  let mut modulus: i128 = 1;
+  for &(r, m) in congruences {
+    let (r, m) = (r.to_i128()?, m.to_i128()?);
+    if m <= 0 {
+      return None;
+    }
+    let (g, p, _) = extended_gcd(modulus, m);
+    let diff = r.checked_sub(x)?;
+    if diff % g != 0 {
+      return None;
+    }
+    let step = m / g;
+    let k = (diff / g % step).checked_mul(p)?.rem_euclid(step);
+    x = x.checked_add(modulus.checked_mul(k)?)?;
+    modulus = modulus.checked_mul(step)?;
+    x = x.rem_euclid(modulus);
+  }
+  Some((T::from(x)?, T::from(modulus)?))
+}
+
+//digit helpers work on non negative numbers, in base 10; the ones returning Option give None
+//for negative input
+
+pub fn digit_count(n: i64) -> u32 {
+  debug_assert!(n >= 0, "digit_count of negative {n}");
+  n.checked_ilog10().unwrap_or(0) + 1
+}
+
+pub fn pow10(exp: u32) -> Option<i64> {
+  10i64.checked_pow(exp)
+}
+
+//1234 -> (12, 34); None for an odd number of digits
+pub fn split_digits(n: i64) -> Option<(i64, i64)> {
+  if n < 0 {
+    return None;
+  }
+  let count = digit_count(n);
+  if !count.is_multiple_of(2) {
+    return None;
+  }
+  let half = pow10(count / 2)?;
+  Some((n / half, n % half))
+}
+
+//(12, 345) -> 12345
+pub fn concat(a: i64, b: i64) -> Option<i64> {
+  if a < 0 || b < 0 {
+    return None;
+  }
+  a.checked_mul(pow10(digit_count(b))?)?.checked_add(b)
+}
+
+//most significant digit first
+#[allow(unused)]
+pub fn digits(n: i64) -> impl Iterator<Item = u8> {
+  debug_assert!(n >= 0, "digits of negative {n}");
+  (0..digit_count(n))
+    .rev()
+    .map(move |i| (n / 10i64.pow(i) % 10) as u8)
+}
+
+#[allow(unused)]
+pub fn reverse_digits(n: i64) -> Option<i64> {
+  if n < 0 {
+    return None;
+  }
+  let mut n = n;
+  let mut reversed: i64 = 0;
+  loop {
+    reversed = reversed.checked_mul(10)?.checked_add(n % 10)?;
+    n /= 10;
+    if n == 0 {
+      return Some(reversed);
+    }
+  }
+}
+
+#[allow(unused)]
+pub fn ends_with(n: i64, suffix: i64) -> bool {
+  strip_suffix(n, suffix).is_some()
+}
+
+//12345 without 45 -> 123, the inverse of concat
+#[allow(unused)]
+pub fn strip_suffix(n: i64, suffix: i64) -> Option<i64> {
+  if n < 0 || suffix < 0 {
+    return None;
+  }
+  let divisor = pow10(digit_count(suffix))?;
+  if n % divisor == suffix {
+    Some(n / divisor)
+  } else {
+    None
+  }
+}
+
+//n choose k, None when it does not fit a u64
+pub fn checked_binomial(n: u64, k: u64) -> Option<u64> {
+  if k > n {
+    return Some(0);
+  }
+  let k = k.min(n - k);
+  let mut result: u128 = 1;
+  for i in 0..k {
+    //result is C(n, i) here, so the division is exact
+    result = result * (n - i) as u128 / (i + 1) as u128;
+    if result > u64::MAX as u128 {
+      return None;
+    }
+  }
+  Some(result as u64)
+}
+
+#[allow(unused)]
+pub fn binomial(n: u64, k: u64) -> u64 {
+  checked_binomial(n, k).expect("binomial overflow")
+}
+
+//every sequence of len symbols, odometer style: the last position changes fastest
+#[allow(unused)]
+pub fn cartesian_power<T: Clone>(symbols: &[T], len: usize) -> impl Iterator<Item = Vec<T>> + '_ {
+  let mut indexes = vec![0; len];
+  let mut done = symbols.is_empty() && len > 0;
+  std::iter::from_fn(move || {
+    if done {
+      return None;
+    }
+    let item = indexes.iter().map(|&i| symbols[i].clone()).collect();
+    done = true;
+    for i in indexes.iter_mut().rev() {
+      *i += 1;
+      if *i < symbols.len() {
+        done = false;
+        break;
+      }
+      *i = 0;
+    }
+    Some(item)
+  })
+}
+
+//sorted k-element subsets of 0..n, in lexicographic order
+pub fn combinations(n: usize, k: usize) -> impl Iterator<Item = Vec<usize>> {
+  let mut current: Option<Vec<usize>> = (k <= n).then(|| (0..k).collect());
+  std::iter::from_fn(move || {
+    let item = current.clone()?;
+    let combination = current.as_mut().unwrap();
+    //rightmost index that can still move right
+    match (0..k).rev().find(|&i| combination[i] < n - k + i) {
+      Some(i) => {
+        combination[i] += 1;
+        for j in i + 1..k {
+          combination[j] = combination[j - 1] + 1;
+        }
+      }
+      None => current = None,
+    }
+    Some(item)
+  })
+}
+
+//orderings of 0..n, in lexicographic order
+#[allow(unused)]
+pub fn permutations(n: usize) -> impl Iterator<Item = Vec<usize>> {
+  let mut current: Option<Vec<usize>> = Some((0..n).collect());
+  std::iter::from_fn(move || {
+    let item = current.clone()?;
+    let permutation = current.as_mut().unwrap();
+    match (1..n).rev().find(|&i| permutation[i - 1] < permutation[i]) {
+      Some(i) => {
+        let pivot = i - 1;
+        let swap = (i..n)
+          .rev()
+          .find(|&j| permutation[j] > permutation[pivot])
+          .unwrap();
+        permutation.swap(pivot, swap);
+        permutation[i..].reverse();
+      }
+      None => current = None,
+    }
+    Some(item)
+  })
+}
+
+//all 2^n subsets of 0..n as bit masks, each differing from the previous one by a single bit;
+//the bit flipped to get there comes along, None for the initial empty set
+#[allow(unused)]
+pub fn gray_code_subsets(n: u32) -> impl Iterator<Item = (u64, Option<usize>)> {
+  assert!(n < 64, "too many elements for a u64 mask");
+  (0..1u64 << n).map(|i| {
+    let flipped = (i > 0).then(|| i.trailing_zeros() as usize);
+    (i ^ (i >> 1), flipped)
+  })
+}
+
+//primes up to limit, included
+#[allow(unused)]
+pub fn sieve(limit: usize) -> Vec<u64> {
+  let mut composite = vec![false; limit + 1];
+  let mut primes = vec![];
+  for n in 2..=limit {
+    if composite[n] {
+      continue;
+    }
+    primes.push(n as u64);
+    for multiple in (n * n..=limit).step_by(n) {
+      composite[multiple] = true;
+    }
+  }
+  primes
+}
+
+//deterministic miller-rabin, these bases are enough for every u64
+pub fn is_prime(n: u64) -> bool {
+  const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+  if n < 2 {
+    return false;
+  }
+  if let Some(&p) = BASES.iter().find(|&&p| n.is_multiple_of(p)) {
+    return n == p;
+  }
+  let shift = (n - 1).trailing_zeros();
+  let d = (n - 1) >> shift;
+  'bases: for a in BASES {
+    let mut x = mod_pow(a, d, n);
+    if x == 1 || x == n - 1 {
+      continue;
+    }
+    for _ in 1..shift {
+      x = mod_pow(x, 2, n);
+      if x == n - 1 {
+        continue 'bases;
+      }
+    }
+    return false;
+  }
+  true
+}
+
+//(prime, exponent) pairs sorted by prime, empty for 0 and 1
+#[allow(unused)]
+pub fn trial_division(mut n: u64) -> Vec<(u64, u32)> {
+  let mut factors = vec![];
+  if n == 0 {
+    return factors;
+  }
+  let mut p = 2;
+  while p <= n / p {
+    if n.is_multiple_of(p) {
+      let mut exp = 0;
+      while n.is_multiple_of(p) {
+        n /= p;
+        exp += 1;
+      }
+      factors.push((p, exp));
+    }
+    p += if p == 2 { 1 } else { 2 };
+  }
+  if n > 1 {
+    factors.push((n, 1));
+  }
+  factors
+}
+
+//some non trivial factor of a composite n
+fn pollard_rho(n: u64) -> u64 {
+  if n.is_multiple_of(2) {
+    return 2;
+  }
+  for c in 1..n {
+    let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+    let (mut x, mut y, mut d) = (2, 2, 1);
+    while d == 1 {
+      x = f(x);
+      y = f(f(y));
+      d = x.abs_diff(y).gcd(&n);
+    }
+    if d != n {
+      return d;
+    }
+  }
+  unreachable!("{n} is prime")
+}
+
+//same result as trial_division, fast even when n has two large prime factors
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+  let mut primes = vec![];
+  let mut pending = if n > 1 { vec![n] } else { vec![] };
+  while let Some(m) = pending.pop() {
+    if is_prime(m) {
+      primes.push(m);
+    } else {
+      let d = pollard_rho(m);
+      pending.push(d);
+      pending.push(m / d);
+    }
+  }
+  primes.sort_unstable();
+  primes
+    .chunk_by(|a, b| a == b)
+    .map(|chunk| (chunk[0], chunk.len() as u32))
+    .collect()
+}
+
+//sorted, empty for 0
+#[allow(unused)]
+pub fn divisors(n: u64) -> Vec<u64> {
+  if n == 0 {
+    return vec![];
+  }
+  let mut divisors = vec![1];
+  for (p, exp) in factorize(n) {
+    let previous = divisors.len();
+    let mut power = 1;
+    for _ in 0..exp {
+      power *= p;
+      for i in 0..previous {
+        divisors.push(divisors[i] * power);
+      }
+    }
+  }
+  divisors.sort_unstable();
+  divisors
+}
+
+//count of values in 1..=n coprime with n
+#[allow(unused)]
+pub fn totient(n: u64) -> u64 {
+  factorize(n)
+    .into_iter()
+    .fold(n, |result, (p, _)| result / p * (p - 1))
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -27,4 +502,180 @@ mod tests {
     assert_eq!(n_natural_sum_range(Range::new(1, 10)), 55);
     assert_eq!(n_natural_sum_range(Range::new(2, 2)), 5);
   }
+
+  #[test]
+  fn gcd_lcm() {
+    assert_eq!(gcd_all(&[12, 18, 30]), 6);
+    assert_eq!(lcm_all(&[4u64, 6, 10]), 60);
+    assert_eq!(gcd_all::<i64>(&[]), 0);
+    let (g, x, y) = extended_gcd(240i64, 46);
+    assert_eq!(g, 2);
+    assert_eq!(240 * x + 46 * y, 2);
+  }
+
+  #[test]
+  fn modular() {
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3i64, 11), Some(7));
+    assert_eq!(mod_inverse(6, 9), None);
+    assert_eq!(mod_inverse(3i64, 0), None);
+    assert_eq!(mod_inverse(3i64, -11), None);
+    assert_eq!(mod_pow(4u32, 13, 497), 445);
+    assert_eq!(mod_pow(2i64, 100, 1_000_000_007), 976371285);
+    assert_eq!(mod_pow(5u64, 0, 1), 0);
+    let m = u64::MAX - 58;
+    assert_eq!(mod_pow(m - 1, 2, m), 1);
+    assert_eq!(mod_pow(3u64, m - 1, m), 1);
+    assert_eq!(mod_pow(u64::MAX, 3, u64::MAX - 1), 1);
+  }
+
+  #[test]
+  fn chinese_remainder() {
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt(&[(3i64, 4), (1, 6)]), Some((7, 12)));
+    assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+    assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+    assert_eq!(crt(&[(1i64, 0)]), None);
+    assert_eq!(crt(&[(1i64, 3), (1, -5)]), None);
+    let big = [(1i64, i64::MAX), (2, i64::MAX - 1), (3, i64::MAX - 2)];
+    assert_eq!(crt(&big), None);
+    assert_eq!(crt::<i128>(&big.map(|(r, m)| (r as i128, m as i128))), None);
+  }
+
+  #[test]
+  fn digit_helpers() {
+    assert_eq!(digit_count(0), 1);
+    assert_eq!(digit_count(9), 1);
+    assert_eq!(digit_count(10), 2);
+    assert_eq!(digit_count(i64::MAX), 19);
+    assert_eq!(pow10(18), Some(1_000_000_000_000_000_000));
+    assert_eq!(pow10(19), None);
+    assert_eq!(split_digits(1234), Some((12, 34)));
+    assert_eq!(split_digits(1000), Some((10, 0)));
+    assert_eq!(split_digits(123), None);
+    assert_eq!(concat(12, 345), Some(12345));
+    assert_eq!(concat(15, 0), Some(150));
+    assert_eq!(concat(i64::MAX / 10, 99), None);
+    assert_eq!(digits(9051).collect::<Vec<_>>(), vec![9, 0, 5, 1]);
+    assert_eq!(reverse_digits(1230), Some(321));
+    assert_eq!(reverse_digits(1_000_000_000_000_000_099), None);
+    assert!(ends_with(12345, 45));
+    assert!(!ends_with(12345, 35));
+    assert_eq!(strip_suffix(12345, 345), Some(12));
+    assert_eq!(strip_suffix(7, 7), Some(0));
+    assert_eq!(concat(5, -3), None);
+    assert_eq!(concat(-5, 3), None);
+    assert_eq!(split_digits(-1234), None);
+    assert_eq!(reverse_digits(-12), None);
+    assert_eq!(strip_suffix(-12345, 45), None);
+    assert!(!ends_with(12345, -45));
+  }
+
+  #[test]
+  #[should_panic(expected = "negative")]
+  fn negative_digit_count() {
+    digit_count(-12345);
+  }
+
+  #[test]
+  fn series() {
+    assert_eq!(arithmetic_sum(1, 1, 10), 55);
+    assert_eq!(arithmetic_sum(10, -3, 4), 22);
+    assert_eq!(checked_arithmetic_sum(i64::MAX, 1, 2), None);
+    assert_eq!(checked_arithmetic_sum(0, i64::MAX, i64::MAX), None);
+    assert_eq!(checked_arithmetic_sum(i64::MIN, i64::MIN, i64::MAX), None);
+    assert_eq!(checked_arithmetic_sum(5, 1, -3), None);
+    assert_eq!(arithmetic_sum(5, 1, 0), 0);
+    assert_eq!(geometric_sum(3, 2, 4), 45);
+    assert_eq!(geometric_sum(5, 1, 3), 15);
+    assert_eq!(geometric_sum(1, -2, 3), 3);
+    assert_eq!(checked_geometric_sum(1, 10, 20), None);
+    assert_eq!(sum_of_squares(10), 385);
+    assert_eq!(sum_of_squares(0), 0);
+    assert_eq!(checked_sum_of_squares(i64::MAX), None);
+    assert_eq!(checked_sum_of_squares(-4), None);
+    assert_eq!(stepped_sum(2, 11, 3), 2 + 5 + 8);
+    assert_eq!(stepped_sum(5, 5, 3), 0);
+    assert_eq!(checked_stepped_sum(0, 5, 0), None);
+    assert_eq!(checked_stepped_sum(i64::MIN, i64::MAX, i64::MAX), Some(-3));
+    assert_eq!(weighted_range_sum(3, Range::new(4, 3)), 3 * (4 + 5 + 6));
+    assert_eq!(checked_weighted_range_sum(i64::MAX, Range::new(1, 2)), None);
+  }
+
+  #[test]
+  fn counting() {
+    assert_eq!(binomial(5, 2), 10);
+    assert_eq!(binomial(5, 7), 0);
+    assert_eq!(binomial(0, 0), 1);
+    assert_eq!(binomial(62, 31), 465428353255261088);
+    assert_eq!(checked_binomial(68, 34), None);
+    assert_eq!(binomial(u64::MAX, 1), u64::MAX);
+  }
+
+  #[test]
+  fn enumerations() {
+    let words: Vec<String> = cartesian_power(&['a', 'b'], 2)
+      .map(|w| w.into_iter().collect())
+      .collect();
+    assert_eq!(words, vec!["aa", "ab", "ba", "bb"]);
+    assert_eq!(cartesian_power(&[1, 2, 3], 4).count(), 81);
+    assert_eq!(cartesian_power(&[1, 2], 0).count(), 1);
+    assert_eq!(cartesian_power::<u8>(&[], 2).count(), 0);
+
+    let pairs: Vec<Vec<usize>> = combinations(4, 2).collect();
+    assert_eq!(pairs[0], vec![0, 1]);
+    assert_eq!(pairs[5], vec![2, 3]);
+    assert_eq!(pairs.len(), 6);
+    assert_eq!(combinations(10, 4).count() as u64, binomial(10, 4));
+    assert_eq!(combinations(3, 0).count(), 1);
+    assert_eq!(combinations(2, 3).count(), 0);
+
+    let orders: Vec<Vec<usize>> = permutations(3).collect();
+    assert_eq!(orders.len(), 6);
+    assert_eq!(orders[1], vec![0, 2, 1]);
+    assert_eq!(orders[5], vec![2, 1, 0]);
+
+    let subsets: Vec<(u64, Option<usize>)> = gray_code_subsets(3).collect();
+    assert_eq!(subsets.len(), 8);
+    assert_eq!(subsets[0], (0, None));
+    for w in subsets.windows(2) {
+      let (previous, (mask, flipped)) = (w[0].0, w[1]);
+      assert_eq!(previous ^ mask, 1 << flipped.unwrap());
+    }
+  }
+
+  #[test]
+  fn primes() {
+    assert_eq!(sieve(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert_eq!(sieve(1), vec![]);
+    let small = sieve(10_000);
+    for n in 0..=10_000 {
+      assert_eq!(is_prime(n), small.binary_search(&n).is_ok());
+    }
+    assert!(is_prime(18446744073709551557));
+    assert!(!is_prime(561));
+    assert!(!is_prime(3215031751));
+  }
+
+  #[test]
+  fn factorisation() {
+    for n in 0..3000 {
+      assert_eq!(factorize(n), trial_division(n));
+    }
+    assert_eq!(
+      factorize(600851475143),
+      vec![(71, 1), (839, 1), (1471, 1), (6857, 1)]
+    );
+    assert_eq!(
+      factorize(18446743979220271189),
+      vec![(4294967279, 1), (4294967291, 1)]
+    );
+    assert_eq!(trial_division(1 << 40), vec![(2, 40)]);
+    assert_eq!(divisors(12), vec![1, 2, 3, 4, 6, 12]);
+    assert_eq!(divisors(1), vec![1]);
+    assert_eq!(divisors(0), vec![]);
+    assert_eq!(totient(36), 12);
+    assert_eq!(totient(1), 1);
+    assert_eq!(totient(97), 96);
+  }
 }
@@ -0,0 +1,43 @@
+use std::num::ParseIntError;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+  static ref INTEGER_REGEX: Regex = Regex::new(r"-?\d+").unwrap();
+}
+
+//every signed integer in the text, e.g. "p=0,4 v=3,-3" -> [0, 4, 3, -3];
+//a minus right after a digit is a separator, so "10-20" -> [10, 20]
+pub fn extract_integers(text: &str) -> Result<Vec<i64>, ParseIntError> {
+  INTEGER_REGEX
+    .find_iter(text)
+    .map(|m| {
+      let after_digit = text[..m.start()]
+        .bytes()
+        .next_back()
+        .is_some_and(|b| b.is_ascii_digit());
+      let number = m.as_str();
+      if after_digit {
+        number[1..].parse()
+      } else {
+        number.parse()
+      }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn integers() {
+    assert_eq!(extract_integers("p=0,4 v=3,-3"), Ok(vec![0, 4, 3, -3]));
+    assert_eq!(extract_integers("x=12, y=5"), Ok(vec![12, 5]));
+    assert_eq!(extract_integers("nothing here"), Ok(vec![]));
+    assert_eq!(extract_integers("10-20,-5"), Ok(vec![10, 20, -5]));
+    assert_eq!(extract_integers("x=-1..-3"), Ok(vec![-1, -3]));
+    assert!(extract_integers("99999999999999999999").is_err());
+  }
+}
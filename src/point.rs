@@ -1,9 +1,24 @@
+use crate::grid::Grid;
+use crate::parse::extract_integers;
+use num::integer::gcd;
+use num::{PrimInt, Signed};
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::num::ParseIntError;
 use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
+//integer types usable as point coordinates
+pub trait Coord: PrimInt + Signed + Hash + Debug + Default {}
+
+impl<T: PrimInt + Signed + Hash + Debug + Default> Coord for T {}
+
+//i64 by default; smaller types such as Point<i16> are handy for big visited sets
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Point {
-  pub x: i64,
-  pub y: i64,
+pub struct Point<T = i64> {
+  pub x: T,
+  pub y: T,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -12,52 +27,199 @@ pub struct Bounds {
   max: Point,
 }
 
-#[allow(unused)]
-impl Point {
-  pub const fn new(x: i64, y: i64) -> Self {
-    Self { x, y }
-  }
+static ORTHOGONAL_DIRECTIONS: [Point; 4] = [
+  Point::new(0, -1),
+  Point::new(1, 0),
+  Point::new(0, 1),
+  Point::new(-1, 0),
+];
 
-  pub fn is_in_bounds(&self, bounds: &Bounds) -> bool {
-    self.x >= bounds.min.x
-      && self.x <= bounds.max.x
-      && self.y >= bounds.min.y
-      && self.y <= bounds.max.y
+static DIAGONAL_DIRECTIONS: [Point; 4] = [
+  Point::new(1, -1),
+  Point::new(1, 1),
+  Point::new(-1, 1),
+  Point::new(-1, -1),
+];
+
+impl<T> Point<T> {
+  pub const fn new(x: T, y: T) -> Self {
+    Self { x, y }
   }
+}
 
+#[allow(unused)]
+impl<T: Coord> Point<T> {
   pub fn opposite(&self) -> Self {
     -*self
   }
 
-  pub const fn zero() -> Self {
-    Self { x: 0, y: 0 }
+  pub fn zero() -> Self {
+    Self::new(T::zero(), T::zero())
   }
 
-  pub const fn same_x(self, other: Self) -> bool {
+  pub fn same_x(self, other: Self) -> bool {
     self.x == other.x
   }
 
-  pub const fn same_y(self, other: Self) -> bool {
+  pub fn same_y(self, other: Self) -> bool {
     self.y == other.y
   }
 
-  pub const fn same_line(self, other: Self) -> bool {
+  pub fn same_line(self, other: Self) -> bool {
     self.same_x(other) || self.same_y(other)
   }
 
-  pub const fn normalize(self) -> Self {
+  pub fn normalize(self) -> Self {
+    Self::new(self.x.signum(), self.y.signum())
+  }
+
+  pub fn to_tuple(self) -> (T, T) {
+    (self.x, self.y)
+  }
+
+  //lossless conversion to a wider coordinate type
+  pub fn cast<U: From<T>>(self) -> Point<U> {
+    Point::new(U::from(self.x), U::from(self.y))
+  }
+
+  pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+    Some(Point::new(
+      U::try_from(self.x).ok()?,
+      U::try_from(self.y).ok()?,
+    ))
+  }
+
+  pub fn from_indexes(x: usize, y: usize) -> Option<Self> {
+    Some(Self::new(T::from(x)?, T::from(y)?))
+  }
+
+  //(column, row) when both coordinates are non negative
+  pub fn to_indexes(self) -> Option<(usize, usize)> {
+    Some((self.x.to_usize()?, self.y.to_usize()?))
+  }
+
+  pub fn get_in<V>(self, rows: &[Vec<V>]) -> Option<&V> {
+    let (x, y) = self.to_indexes()?;
+    rows.get(y)?.get(x)
+  }
+
+  pub fn manhattan(self, other: Self) -> T {
+    (self.x - other.x).abs() + (self.y - other.y).abs()
+  }
+
+  pub fn chebyshev(self, other: Self) -> T {
+    (self.x - other.x).abs().max((self.y - other.y).abs())
+  }
+
+  pub fn dot(self, other: Self) -> T {
+    self.x * other.x + self.y * other.y
+  }
+
+  pub fn cross(self, other: Self) -> T {
+    self.x * other.y - self.y * other.x
+  }
+
+  //rotations assume screen coordinates, where y grows downwards
+  pub fn rotate_cw(self) -> Self {
+    Self::new(-self.y, self.x)
+  }
+
+  pub fn rotate_ccw(self) -> Self {
+    Self::new(self.y, -self.x)
+  }
+
+  pub fn rotate_180(self) -> Self {
+    -self
+  }
+
+  pub fn rotate_cw_around(self, center: Self) -> Self {
+    (self - center).rotate_cw() + center
+  }
+
+  pub fn rotate_ccw_around(self, center: Self) -> Self {
+    (self - center).rotate_ccw() + center
+  }
+
+  pub fn rotate_180_around(self, center: Self) -> Self {
+    (self - center).rotate_180() + center
+  }
+}
+
+#[allow(unused)]
+impl Point {
+  pub fn is_in_bounds(&self, bounds: &Bounds) -> bool {
+    self.x >= bounds.min.x
+      && self.x <= bounds.max.x
+      && self.y >= bounds.min.y
+      && self.y <= bounds.max.y
+  }
+
+  pub const fn rem_euclid(self, modulus: Self) -> Self {
     Self {
-      x: self.x.signum(),
-      y: self.y.signum(),
+      x: self.x.rem_euclid(modulus.x),
+      y: self.y.rem_euclid(modulus.y),
     }
   }
 
-  pub const fn to_tuple(self) -> (i64, i64) {
-    (self.x, self.y)
+  //the four cells sharing an edge with this one
+  pub fn neighbours(self) -> impl Iterator<Item = Point> {
+    ORTHOGONAL_DIRECTIONS.iter().map(move |&d| self + d)
+  }
+
+  //the eight cells sharing an edge or a corner with this one
+  pub fn all_neighbours(self) -> impl Iterator<Item = Point> {
+    ORTHOGONAL_DIRECTIONS
+      .iter()
+      .chain(DIAGONAL_DIRECTIONS.iter())
+      .map(move |&d| self + d)
+  }
+
+  //smallest integer step with the same direction, e.g. (4, -6) -> (2, -3)
+  pub fn reduced(self) -> Self {
+    let divisor = gcd(self.x, self.y);
+    if divisor == 0 {
+      self
+    } else {
+      Self::new(self.x / divisor, self.y / divisor)
+    }
+  }
+
+  //every lattice point lying exactly on the segment, both ends included
+  pub fn line_to(self, other: Self) -> impl Iterator<Item = Point> {
+    let diff = other - self;
+    let step = diff.reduced();
+    let count = gcd(diff.x, diff.y);
+    (0..=count).map(move |i| self + step * i)
+  }
+
+  //bresenham approximation of the segment, both ends included
+  pub fn raster_line_to(self, other: Self) -> Vec<Point> {
+    let dx = (other.x - self.x).abs();
+    let dy = -(other.y - self.y).abs();
+    let step = (other - self).normalize();
+    let mut points = Vec::with_capacity((dx.max(-dy) + 1) as usize);
+    let mut current = self;
+    let mut error = dx + dy;
+    loop {
+      points.push(current);
+      if current == other {
+        break;
+      }
+      let doubled = 2 * error;
+      if doubled >= dy {
+        error += dy;
+        current.x += step.x;
+      }
+      if doubled <= dx {
+        error += dx;
+        current.y += step.y;
+      }
+    }
+    points
   }
 }
 
-impl Neg for Point {
+impl<T: Coord> Neg for Point<T> {
   type Output = Self;
 
   fn neg(self) -> Self {
@@ -68,7 +230,7 @@ impl Neg for Point {
   }
 }
 
-impl Add for Point {
+impl<T: Coord> Add for Point<T> {
   type Output = Self;
 
   fn add(self, other: Self) -> Self {
@@ -79,13 +241,13 @@ impl Add for Point {
   }
 }
 
-impl AddAssign for Point {
+impl<T: Coord> AddAssign for Point<T> {
   fn add_assign(&mut self, other: Self) {
     *self = *self + other;
   }
 }
 
-impl Sub for Point {
+impl<T: Coord> Sub for Point<T> {
   type Output = Self;
 
   fn sub(self, other: Self) -> Self {
@@ -96,16 +258,16 @@ impl Sub for Point {
   }
 }
 
-impl SubAssign for Point {
+impl<T: Coord> SubAssign for Point<T> {
   fn sub_assign(&mut self, other: Self) {
     *self = *self - other;
   }
 }
 
-impl Mul<i64> for Point {
+impl<T: Coord> Mul<T> for Point<T> {
   type Output = Self;
 
-  fn mul(self, other: i64) -> Self {
+  fn mul(self, other: T) -> Self {
     Self {
       x: self.x * other,
       y: self.y * other,
@@ -113,12 +275,97 @@ impl Mul<i64> for Point {
   }
 }
 
-impl MulAssign<i64> for Point {
-  fn mul_assign(&mut self, other: i64) {
+impl<T: Coord> MulAssign<T> for Point<T> {
+  fn mul_assign(&mut self, other: T) {
     *self = *self * other;
   }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePointError {
+  Int(ParseIntError),
+  Count { expected: usize, found: usize },
+  OddCount { found: usize },
+}
+
+impl Display for ParsePointError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ParsePointError::Int(e) => write!(f, "invalid coordinate: {e}"),
+      ParsePointError::Count { expected, found } => {
+        write!(f, "expected {expected} integers, found {found}")
+      }
+      ParsePointError::OddCount { found } => {
+        write!(f, "expected an even number of integers, found {found}")
+      }
+    }
+  }
+}
+
+impl Error for ParsePointError {}
+
+impl From<ParseIntError> for ParsePointError {
+  fn from(e: ParseIntError) -> Self {
+    ParsePointError::Int(e)
+  }
+}
+
+fn extract_exact<const N: usize>(text: &str) -> Result<[i64; N], ParsePointError> {
+  let values = extract_integers(text)?;
+  let found = values.len();
+  values
+    .try_into()
+    .map_err(|_| ParsePointError::Count { expected: N, found })
+}
+
+//builds a point out of every pair of integers, e.g. "p=0,4 v=3,-3" -> [(0, 4), (3, -3)]
+#[allow(unused)]
+pub fn extract_points(text: &str) -> Result<Vec<Point>, ParsePointError> {
+  let values = extract_integers(text)?;
+  if values.len() % 2 != 0 {
+    return Err(ParsePointError::OddCount {
+      found: values.len(),
+    });
+  }
+  Ok(values.chunks(2).map(|c| Point::new(c[0], c[1])).collect())
+}
+
+impl<T: Display> Display for Point<T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{},{}", self.x, self.y)
+  }
+}
+
+//accepts any text holding exactly two integers: "3,-4", "(3, -4)", "x=3, y=-4"
+impl FromStr for Point {
+  type Err = ParsePointError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let [x, y] = extract_exact(s)?;
+    Ok(Point::new(x, y))
+  }
+}
+
+impl Display for Bounds {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}..={}", self.min, self.max)
+  }
+}
+
+//accepts any text holding exactly four integers, min first: "0,0..=10,6"
+impl FromStr for Bounds {
+  type Err = ParsePointError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let [min_x, min_y, max_x, max_y] = extract_exact(s)?;
+    Ok(Bounds::new(
+      Point::new(min_x, min_y),
+      Point::new(max_x, max_y),
+    ))
+  }
+}
+
+#[allow(unused)]
 impl Bounds {
   pub const fn new(min: Point, max: Point) -> Self {
     Self { min, max }
@@ -131,6 +378,101 @@ impl Bounds {
   pub fn from_dims(x: usize, y: usize) -> Self {
     Self::new(Point::new(0, 0), Point::new(x as i64 - 1, y as i64 - 1))
   }
+
+  pub fn from_points<I: IntoIterator<Item = Point>>(points: I) -> Option<Self> {
+    let mut points = points.into_iter();
+    let first = points.next()?;
+    Some(points.fold(Self::new(first, first), |bounds, p| bounds.include(p)))
+  }
+
+  pub const fn min(&self) -> Point {
+    self.min
+  }
+
+  pub const fn max(&self) -> Point {
+    self.max
+  }
+
+  pub const fn width(&self) -> i64 {
+    self.max.x - self.min.x + 1
+  }
+
+  pub const fn height(&self) -> i64 {
+    self.max.y - self.min.y + 1
+  }
+
+  pub const fn area(&self) -> i64 {
+    if self.is_empty() {
+      0
+    } else {
+      self.width() * self.height()
+    }
+  }
+
+  pub const fn is_empty(&self) -> bool {
+    self.max.x < self.min.x || self.max.y < self.min.y
+  }
+
+  //points in row-major order: left to right, then top to bottom
+  pub fn iter(&self) -> impl Iterator<Item = Point> {
+    let Bounds { min, max } = *self;
+    (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+  }
+
+  pub fn include(&self, point: Point) -> Self {
+    Self::new(
+      Point::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+      Point::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+    )
+  }
+
+  //an empty operand adds nothing
+  pub fn union(&self, other: &Self) -> Self {
+    if other.is_empty() {
+      *self
+    } else if self.is_empty() {
+      *other
+    } else {
+      self.include(other.min).include(other.max)
+    }
+  }
+
+  pub fn intersection(&self, other: &Self) -> Option<Self> {
+    let bounds = Self::new(
+      Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+      Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+    );
+    if bounds.is_empty() {
+      None
+    } else {
+      Some(bounds)
+    }
+  }
+
+  pub fn expand(&self, margin: i64) -> Self {
+    let margin = Point::new(margin, margin);
+    Self::new(self.min - margin, self.max + margin)
+  }
+
+  //None for empty bounds, which have no point to clamp to
+  pub fn clamp(&self, point: Point) -> Option<Point> {
+    if self.is_empty() {
+      return None;
+    }
+    Some(Point::new(
+      point.x.clamp(self.min.x, self.max.x),
+      point.y.clamp(self.min.y, self.max.y),
+    ))
+  }
+
+  //wraps the point around the edges, as if the bounds were a torus; None for empty bounds
+  pub fn wrap(&self, point: Point) -> Option<Point> {
+    if self.is_empty() {
+      return None;
+    }
+    let size = Point::new(self.width(), self.height());
+    Some(self.min + (point - self.min).rem_euclid(size))
+  }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -138,6 +480,17 @@ pub struct Matrix2DNavigator {
   pub bounds: Bounds,
   pub current: Point,
   pub direction: Point,
+  pub edge: EdgePolicy,
+}
+
+//what a navigator does when the next step would leave its bounds
+#[allow(unused)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EdgePolicy {
+  #[default]
+  Stop,
+  Wrap,
+  Reflect,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -146,54 +499,435 @@ pub enum PathStatus {
   Partial,
 }
 
+//points visited in order, directions[i] is the step taken from points[i] to points[i + 1]
+//(up to a wrap around the bounds for a wrapping navigator)
+#[allow(unused)]
 #[derive(Debug, Clone)]
 pub struct Path {
   pub points: Vec<Point>,
+  pub directions: Vec<Point>,
   pub status: PathStatus,
 }
 
+#[allow(unused)]
+impl Path {
+  pub fn new(start: Point) -> Self {
+    Self {
+      points: vec![start],
+      directions: vec![],
+      status: PathStatus::Full,
+    }
+  }
+
+  pub fn start(&self) -> Option<Point> {
+    self.points.first().copied()
+  }
+
+  pub fn end(&self) -> Option<Point> {
+    self.points.last().copied()
+  }
+
+  pub fn push(&mut self, direction: Point, point: Point) {
+    self.directions.push(direction);
+    self.points.push(point);
+  }
+
+  //number of steps taken, one less than the number of points
+  pub fn len(&self) -> usize {
+    self.directions.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.directions.is_empty()
+  }
+
+  pub fn turns(&self) -> usize {
+    self.directions.windows(2).filter(|w| w[0] != w[1]).count()
+  }
+
+  //e.g. cost(1, 1000) for a maze where turning is much more expensive than walking
+  pub fn cost(&self, step_cost: i64, turn_cost: i64) -> i64 {
+    self.len() as i64 * step_cost + self.turns() as i64 * turn_cost
+  }
+
+  //(from, direction, to) for every step, in order
+  pub fn steps(&self) -> impl DoubleEndedIterator<Item = (Point, Point, Point)> + '_ {
+    self
+      .directions
+      .iter()
+      .enumerate()
+      .map(|(i, &d)| (self.points[i], d, self.points[i + 1]))
+  }
+
+  //same path walked from the end, every direction is flipped
+  pub fn reversed(&self) -> Self {
+    Self {
+      points: self.points.iter().rev().cloned().collect(),
+      directions: self.directions.iter().rev().map(|d| -*d).collect(),
+      status: self.status,
+    }
+  }
+
+  pub fn draw<T: Clone>(&self, grid: &mut Grid<T>, value: T) {
+    for point in self.points.iter() {
+      if let Some(cell) = grid.get_mut(point) {
+        *cell = value.clone();
+      }
+    }
+  }
+
+  //marks every point but the last one with an arrow pointing to the next point
+  pub fn draw_arrows(&self, grid: &mut Grid<char>) {
+    for (from, direction, _) in self.steps() {
+      let arrow = match direction.normalize().to_tuple() {
+        (0, -1) => '^',
+        (1, 0) => '>',
+        (0, 1) => 'v',
+        (-1, 0) => '<',
+        _ => '*',
+      };
+      if let Some(cell) = grid.get_mut(&from) {
+        *cell = arrow;
+      }
+    }
+  }
+}
+
 impl Matrix2DNavigator {
   pub fn next(&mut self) -> Option<Point> {
-    self.read_next().map(|p| {
-      self.current = p;
-      p
-    })
+    self.advance(1)
   }
 
   pub fn read_next(&self) -> Option<Point> {
-    let next = self.current + self.direction;
-    if self.bounds.check(&next) {
-      Some(next)
-    } else {
-      None
+    self.step(1).map(|(p, _)| p)
+  }
+
+  //moves n steps at once, in constant time for every edge policy
+  pub fn advance(&mut self, n: i64) -> Option<Point> {
+    let (position, direction) = self.step(n)?;
+    self.current = position;
+    self.direction = direction;
+    Some(position)
+  }
+
+  fn step(&self, n: i64) -> Option<(Point, Point)> {
+    if self.bounds.is_empty() {
+      return None;
+    }
+    let next = self.current + self.direction * n;
+    match self.edge {
+      EdgePolicy::Stop => {
+        if self.bounds.check(&next) {
+          Some((next, self.direction))
+        } else {
+          None
+        }
+      }
+      EdgePolicy::Wrap => self.bounds.wrap(next).map(|p| (p, self.direction)),
+      EdgePolicy::Reflect => {
+        let (min, max) = (self.bounds.min(), self.bounds.max());
+        let (x, dx) = reflect_axis(self.current.x, self.direction.x, min.x, max.x, n);
+        let (y, dy) = reflect_axis(self.current.y, self.direction.y, min.y, max.y, n);
+        Some((Point::new(x, y), Point::new(dx, dy)))
+      }
     }
   }
 
+  pub fn start_path(&self) -> Path {
+    Path::new(self.current)
+  }
+
+  //moves one step and records it in path, with the direction after a possible bounce
+  pub fn next_into(&mut self, path: &mut Path) -> Option<Point> {
+    let next = self.next()?;
+    path.push(self.direction, next);
+    Some(next)
+  }
+
   pub fn get_path(&mut self, n: usize) -> Path {
     if n == 0 {
       return Path {
         points: vec![],
+        directions: vec![],
         status: PathStatus::Full,
       };
     }
-    let steps = n - 1;
-    let first = self.current;
-    let mut points = Vec::with_capacity(n);
-    points.push(first);
-    for _ in 0..steps {
-      let next = self.next();
-      if let Some(next) = next {
-        points.push(next);
-      } else {
-        return Path {
-          points,
-          status: PathStatus::Partial,
-        };
+    let mut path = self.start_path();
+    for _ in 1..n {
+      if self.next_into(&mut path).is_none() {
+        path.status = PathStatus::Partial;
+        break;
       }
     }
-    Path {
-      points,
-      status: PathStatus::Full,
+    path
+  }
+}
+
+//unfolds the bouncing movement into a straight line of period 2 * span and folds it back
+fn reflect_axis(position: i64, direction: i64, min: i64, max: i64, n: i64) -> (i64, i64) {
+  if direction == 0 || n == 0 {
+    return (position, direction);
+  }
+  let span = max - min;
+  if span == 0 {
+    let direction = if (direction * n) % 2 == 0 {
+      direction
+    } else {
+      -direction
+    };
+    return (min, direction);
+  }
+  let period = 2 * span;
+  let m = (position - min + direction * n).rem_euclid(period);
+  let folded = if m <= span { m } else { period - m };
+  let ascending = if direction > 0 {
+    m > 0 && m <= span
+  } else {
+    m >= span
+  };
+  let direction = if ascending {
+    direction.abs()
+  } else {
+    -direction.abs()
+  };
+  (min + folded, direction)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn bounds_size() {
+    let bounds = Bounds::from_dims(4, 3);
+    assert_eq!(bounds.width(), 4);
+    assert_eq!(bounds.height(), 3);
+    assert_eq!(bounds.area(), 12);
+    let points: Vec<Point> = bounds.iter().take(5).collect();
+    assert_eq!(points[3], Point::new(3, 0));
+    assert_eq!(points[4], Point::new(0, 1));
+    assert_eq!(bounds.iter().count(), 12);
+  }
+
+  #[test]
+  fn bounds_set_operations() {
+    let a = Bounds::new(Point::new(0, 0), Point::new(3, 3));
+    let b = Bounds::new(Point::new(2, -1), Point::new(5, 1));
+    assert_eq!(
+      a.union(&b),
+      Bounds::new(Point::new(0, -1), Point::new(5, 3))
+    );
+    assert_eq!(
+      a.intersection(&b),
+      Some(Bounds::new(Point::new(2, 0), Point::new(3, 1)))
+    );
+    let c = Bounds::new(Point::new(10, 10), Point::new(11, 11));
+    assert_eq!(a.intersection(&c), None);
+    let empty = Bounds::new(Point::new(5, 5), Point::new(4, 4));
+    assert_eq!(a.union(&empty), a);
+    assert_eq!(empty.union(&a), a);
+    assert_eq!(
+      Bounds::from_points([Point::new(1, 5), Point::new(-2, 3), Point::new(0, 7)]),
+      Some(Bounds::new(Point::new(-2, 3), Point::new(1, 7)))
+    );
+    assert_eq!(Bounds::from_points([]), None);
+  }
+
+  #[test]
+  fn bounds_clamp_and_wrap() {
+    let bounds = Bounds::from_dims(11, 7);
+    assert_eq!(bounds.clamp(Point::new(-3, 9)), Some(Point::new(0, 6)));
+    assert_eq!(bounds.wrap(Point::new(-1, 7)), Some(Point::new(10, 0)));
+    assert_eq!(bounds.wrap(Point::new(23, -15)), Some(Point::new(1, 6)));
+    let empty = Bounds::from_dims(0, 0);
+    assert_eq!(empty.clamp(Point::new(1, 1)), None);
+    assert_eq!(empty.wrap(Point::new(1, 1)), None);
+    assert_eq!(
+      bounds.expand(1),
+      Bounds::new(Point::new(-1, -1), Point::new(11, 7))
+    );
+  }
+
+  #[test]
+  fn navigator_wrap() {
+    let mut navigator = Matrix2DNavigator {
+      bounds: Bounds::from_dims(11, 7),
+      current: Point::new(2, 4),
+      direction: Point::new(2, -3),
+      edge: EdgePolicy::Wrap,
+    };
+    assert_eq!(navigator.next(), Some(Point::new(4, 1)));
+    assert_eq!(navigator.advance(4), Some(Point::new(1, 3)));
+    navigator.edge = EdgePolicy::Stop;
+    assert_eq!(navigator.read_next(), Some(Point::new(3, 0)));
+    assert_eq!(navigator.advance(2), None);
+    assert_eq!(navigator.current, Point::new(1, 3));
+  }
+
+  #[test]
+  fn navigator_reflect() {
+    let start = Matrix2DNavigator {
+      bounds: Bounds::from_dims(5, 4),
+      current: Point::new(1, 2),
+      direction: Point::new(1, -1),
+      edge: EdgePolicy::Reflect,
+    };
+    let mut stepper = start;
+    for n in 1..40 {
+      stepper.next();
+      let mut jumper = start;
+      jumper.advance(n);
+      assert_eq!(jumper.current, stepper.current);
+      assert_eq!(jumper.direction, stepper.direction);
+      assert!(stepper.bounds.check(&stepper.current));
     }
+    let mut empty = Matrix2DNavigator {
+      bounds: Bounds::from_dims(0, 0),
+      ..start
+    };
+    assert_eq!(empty.read_next(), None);
+    assert_eq!(empty.advance(3), None);
+  }
+
+  #[test]
+  fn neighbours() {
+    let p = Point::new(3, 3);
+    assert_eq!(p.neighbours().count(), 4);
+    assert!(p.neighbours().all(|n| n.manhattan(p) == 1));
+    assert_eq!(p.all_neighbours().count(), 8);
+    assert!(p.all_neighbours().all(|n| n.chebyshev(p) == 1));
+  }
+
+  #[test]
+  fn distances_and_products() {
+    let a = Point::new(1, -2);
+    let b = Point::new(-3, 5);
+    assert_eq!(a.manhattan(b), 11);
+    assert_eq!(a.chebyshev(b), 7);
+    assert_eq!(a.dot(b), -13);
+    assert_eq!(a.cross(b), -1);
+  }
+
+  #[test]
+  fn rotations() {
+    let up = Point::new(0, -1);
+    assert_eq!(up.rotate_cw(), Point::new(1, 0));
+    assert_eq!(up.rotate_ccw(), Point::new(-1, 0));
+    assert_eq!(up.rotate_cw().rotate_cw(), up.rotate_180());
+    let center = Point::new(2, 2);
+    assert_eq!(Point::new(2, 0).rotate_cw_around(center), Point::new(4, 2));
+    assert_eq!(Point::new(2, 0).rotate_ccw_around(center), Point::new(0, 2));
+    assert_eq!(Point::new(3, 1).rotate_180_around(center), Point::new(1, 3));
+  }
+
+  #[test]
+  fn coordinate_types() {
+    let small: Point<i16> = Point::new(3, -4);
+    assert_eq!(small + small * 2, Point::new(9, -12));
+    assert_eq!(small.manhattan(Point::zero()), 7);
+    let wide: Point = small.cast();
+    assert_eq!(wide, Point::new(3, -4));
+    assert_eq!(Point::new(300i64, 1).try_cast::<i8>(), None);
+    assert_eq!(Point::<i32>::from_indexes(2, 1), Some(Point::new(2, 1)));
+    assert_eq!(small.to_indexes(), None);
+    let rows = vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']];
+    assert_eq!(Point::new(2, 1).get_in(&rows), Some(&'f'));
+    assert_eq!(Point::new(-1, 0).get_in(&rows), None);
+  }
+
+  #[test]
+  fn lines() {
+    assert_eq!(Point::new(4, -6).reduced(), Point::new(2, -3));
+    assert_eq!(Point::zero().reduced(), Point::zero());
+    let points: Vec<Point> = Point::new(0, 0).line_to(Point::new(6, 3)).collect();
+    assert_eq!(
+      points,
+      vec![
+        Point::new(0, 0),
+        Point::new(2, 1),
+        Point::new(4, 2),
+        Point::new(6, 3)
+      ]
+    );
+    let raster = Point::new(0, 0).raster_line_to(Point::new(4, -2));
+    assert_eq!(raster.len(), 5);
+    assert_eq!(raster[2], Point::new(2, -1));
+    assert_eq!(raster[4], Point::new(4, -2));
+  }
+
+  #[test]
+  fn paths() {
+    let mut navigator = Matrix2DNavigator {
+      bounds: Bounds::from_dims(4, 4),
+      current: Point::new(0, 0),
+      direction: Point::new(1, 0),
+      edge: EdgePolicy::Stop,
+    };
+    let mut path = navigator.get_path(3);
+    navigator.direction = navigator.direction.rotate_cw();
+    navigator.next_into(&mut path);
+    navigator.next_into(&mut path);
+    assert_eq!(path.status, PathStatus::Full);
+    assert_eq!(path.len(), 4);
+    assert_eq!(path.turns(), 1);
+    assert_eq!(path.cost(1, 1000), 1004);
+    assert_eq!(path.end(), Some(Point::new(2, 2)));
+
+    let reversed = path.reversed();
+    assert_eq!(reversed.start(), Some(Point::new(2, 2)));
+    assert_eq!(reversed.directions[0], Point::new(0, -1));
+    assert_eq!(reversed.turns(), 1);
+
+    let mut grid = Grid::filled(4, 3, '.');
+    path.draw_arrows(&mut grid);
+    let text: Vec<String> = grid.rows().map(|r| r.iter().collect()).collect();
+    assert_eq!(text, [">>v.", "..v.", "...."]);
+
+    let partial = navigator.get_path(5);
+    assert_eq!(partial.status, PathStatus::Partial);
+    assert_eq!(partial.len(), 1);
+
+    let mut bouncing = Matrix2DNavigator {
+      bounds: Bounds::from_dims(3, 3),
+      current: Point::new(1, 1),
+      direction: Point::new(1, 0),
+      edge: EdgePolicy::Reflect,
+    };
+    let path = bouncing.get_path(4);
+    assert_eq!(path.len(), 3);
+    assert!(path.steps().all(|(from, d, to)| from + d == to));
+    assert_eq!(path.turns(), 1);
+    let mut grid = Grid::filled(3, 3, '.');
+    path.draw_arrows(&mut grid);
+    assert_eq!(grid.row(1).iter().collect::<String>(), ".<<");
+  }
+
+  #[test]
+  fn parsing() {
+    assert_eq!("3,-4".parse(), Ok(Point::new(3, -4)));
+    assert_eq!("x=12, y=5".parse(), Ok(Point::new(12, 5)));
+    assert_eq!(
+      "1,2,3".parse::<Point>(),
+      Err(ParsePointError::Count {
+        expected: 2,
+        found: 3
+      })
+    );
+    assert!(matches!(
+      "99999999999999999999,0".parse::<Point>(),
+      Err(ParsePointError::Int(_))
+    ));
+    assert_eq!(Point::new(-1, 7).to_string(), "-1,7");
+    let bounds = Bounds::from_dims(11, 7);
+    assert_eq!(bounds.to_string(), "0,0..=10,6");
+    assert_eq!(bounds.to_string().parse(), Ok(bounds));
+    assert_eq!(
+      extract_points("p=0,4 v=3,-3"),
+      Ok(vec![Point::new(0, 4), Point::new(3, -3)])
+    );
+    assert_eq!(
+      extract_points("p=0,4 v=3"),
+      Err(ParsePointError::OddCount { found: 3 })
+    );
   }
 }
@@ -0,0 +1,244 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::point_n::PointN;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+  pub x: i64,
+  pub y: i64,
+  pub z: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds3 {
+  min: Point3,
+  max: Point3,
+}
+
+static FACE_DIRECTIONS: [Point3; 6] = [
+  Point3::new(1, 0, 0),
+  Point3::new(-1, 0, 0),
+  Point3::new(0, 1, 0),
+  Point3::new(0, -1, 0),
+  Point3::new(0, 0, 1),
+  Point3::new(0, 0, -1),
+];
+
+#[allow(unused)]
+impl Point3 {
+  pub const fn new(x: i64, y: i64, z: i64) -> Self {
+    Self { x, y, z }
+  }
+
+  pub const fn zero() -> Self {
+    Self { x: 0, y: 0, z: 0 }
+  }
+
+  pub fn is_in_bounds(&self, bounds: &Bounds3) -> bool {
+    self.x >= bounds.min.x
+      && self.x <= bounds.max.x
+      && self.y >= bounds.min.y
+      && self.y <= bounds.max.y
+      && self.z >= bounds.min.z
+      && self.z <= bounds.max.z
+  }
+
+  pub const fn manhattan(self, other: Self) -> i64 {
+    (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+  }
+
+  pub const fn to_tuple(self) -> (i64, i64, i64) {
+    (self.x, self.y, self.z)
+  }
+
+  //the six cubes sharing a face with this one
+  pub fn neighbours(self) -> impl Iterator<Item = Point3> {
+    FACE_DIRECTIONS.iter().map(move |&d| self + d)
+  }
+
+  //the 26 cubes sharing a face, an edge or a corner with this one
+  pub fn all_neighbours(self) -> impl Iterator<Item = Point3> {
+    (-1..=1)
+      .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Point3::new(x, y, z))))
+      .filter(|&d| d != Point3::zero())
+      .map(move |d| self + d)
+  }
+}
+
+impl Neg for Point3 {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    Self {
+      x: -self.x,
+      y: -self.y,
+      z: -self.z,
+    }
+  }
+}
+
+impl Add for Point3 {
+  type Output = Self;
+
+  fn add(self, other: Self) -> Self {
+    Self {
+      x: self.x + other.x,
+      y: self.y + other.y,
+      z: self.z + other.z,
+    }
+  }
+}
+
+impl AddAssign for Point3 {
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl Sub for Point3 {
+  type Output = Self;
+
+  fn sub(self, other: Self) -> Self {
+    Self {
+      x: self.x - other.x,
+      y: self.y - other.y,
+      z: self.z - other.z,
+    }
+  }
+}
+
+impl SubAssign for Point3 {
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl Mul<i64> for Point3 {
+  type Output = Self;
+
+  fn mul(self, other: i64) -> Self {
+    Self {
+      x: self.x * other,
+      y: self.y * other,
+      z: self.z * other,
+    }
+  }
+}
+
+impl MulAssign<i64> for Point3 {
+  fn mul_assign(&mut self, other: i64) {
+    *self = *self * other;
+  }
+}
+
+impl From<PointN<3>> for Point3 {
+  fn from(point: PointN<3>) -> Self {
+    let [x, y, z] = point.coords;
+    Self::new(x, y, z)
+  }
+}
+
+impl From<Point3> for PointN<3> {
+  fn from(point: Point3) -> Self {
+    PointN::new([point.x, point.y, point.z])
+  }
+}
+
+#[allow(unused)]
+impl Bounds3 {
+  pub const fn new(min: Point3, max: Point3) -> Self {
+    Self { min, max }
+  }
+
+  pub fn check(&self, point: &Point3) -> bool {
+    point.is_in_bounds(self)
+  }
+
+  pub fn from_dims(x: usize, y: usize, z: usize) -> Self {
+    Self::new(
+      Point3::zero(),
+      Point3::new(x as i64 - 1, y as i64 - 1, z as i64 - 1),
+    )
+  }
+
+  pub fn from_points<I: IntoIterator<Item = Point3>>(points: I) -> Option<Self> {
+    let mut points = points.into_iter();
+    let first = points.next()?;
+    Some(points.fold(Self::new(first, first), |bounds, p| bounds.include(p)))
+  }
+
+  pub const fn min(&self) -> Point3 {
+    self.min
+  }
+
+  pub const fn max(&self) -> Point3 {
+    self.max
+  }
+
+  pub fn include(&self, point: Point3) -> Self {
+    Self::new(
+      Point3::new(
+        self.min.x.min(point.x),
+        self.min.y.min(point.y),
+        self.min.z.min(point.z),
+      ),
+      Point3::new(
+        self.max.x.max(point.x),
+        self.max.y.max(point.y),
+        self.max.z.max(point.z),
+      ),
+    )
+  }
+
+  pub fn expand(&self, margin: i64) -> Self {
+    let margin = Point3::new(margin, margin, margin);
+    Self::new(self.min - margin, self.max + margin)
+  }
+
+  pub const fn volume(&self) -> i64 {
+    let size = Point3::new(
+      self.max.x - self.min.x + 1,
+      self.max.y - self.min.y + 1,
+      self.max.z - self.min.z + 1,
+    );
+    if size.x <= 0 || size.y <= 0 || size.z <= 0 {
+      0
+    } else {
+      size.x * size.y * size.z
+    }
+  }
+
+  //points ordered by z, then y, then x
+  pub fn iter(&self) -> impl Iterator<Item = Point3> {
+    let Bounds3 { min, max } = *self;
+    (min.z..=max.z).flat_map(move |z| {
+      (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point3::new(x, y, z)))
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn neighbours() {
+    let p = Point3::new(1, 2, 3);
+    assert_eq!(p.neighbours().count(), 6);
+    assert!(p.neighbours().all(|n| n.manhattan(p) == 1));
+    assert_eq!(p.all_neighbours().count(), 26);
+    assert!(!p.all_neighbours().any(|n| n == p));
+  }
+
+  #[test]
+  fn bounds() {
+    let bounds = Bounds3::from_points([Point3::new(0, 0, 0), Point3::new(2, 1, -1)]).unwrap();
+    assert_eq!(bounds.volume(), 12);
+    assert_eq!(bounds.iter().count(), 12);
+    assert!(bounds.check(&Point3::new(1, 1, 0)));
+    assert!(!bounds.check(&Point3::new(1, 1, 1)));
+    assert_eq!(bounds.expand(1).volume(), 5 * 4 * 4);
+    let p: PointN<3> = Point3::new(1, 2, 3).into();
+    assert_eq!(Point3::from(p), Point3::new(1, 2, 3));
+  }
+}
@@ -0,0 +1,216 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::point::Point;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PointN<const D: usize> {
+  pub coords: [i64; D],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundsN<const D: usize> {
+  min: PointN<D>,
+  max: PointN<D>,
+}
+
+#[allow(unused)]
+impl<const D: usize> PointN<D> {
+  pub const fn new(coords: [i64; D]) -> Self {
+    Self { coords }
+  }
+
+  pub const fn zero() -> Self {
+    Self { coords: [0; D] }
+  }
+
+  //unit vector along the given axis
+  pub fn unit(axis: usize) -> Self {
+    let mut point = Self::zero();
+    point.coords[axis] = 1;
+    point
+  }
+
+  pub fn is_in_bounds(&self, bounds: &BoundsN<D>) -> bool {
+    (0..D).all(|i| self.coords[i] >= bounds.min.coords[i] && self.coords[i] <= bounds.max.coords[i])
+  }
+
+  pub fn manhattan(self, other: Self) -> i64 {
+    (0..D)
+      .map(|i| (self.coords[i] - other.coords[i]).abs())
+      .sum()
+  }
+
+  //the 2 * D points differing by one along a single axis
+  pub fn neighbours(self) -> impl Iterator<Item = PointN<D>> {
+    (0..D).flat_map(move |axis| {
+      let unit = Self::unit(axis);
+      [self + unit, self - unit]
+    })
+  }
+
+  //the 3^D - 1 points differing by at most one along every axis
+  pub fn all_neighbours(self) -> impl Iterator<Item = PointN<D>> {
+    let count = 3usize.pow(D as u32);
+    (0..count)
+      .map(|mut n| {
+        let mut delta = Self::zero();
+        for c in delta.coords.iter_mut() {
+          *c = (n % 3) as i64 - 1;
+          n /= 3;
+        }
+        delta
+      })
+      .filter(|&d| d != Self::zero())
+      .map(move |d| self + d)
+  }
+
+  fn zip_with(self, other: Self, f: impl Fn(i64, i64) -> i64) -> Self {
+    let mut coords = self.coords;
+    for (c, o) in coords.iter_mut().zip(other.coords) {
+      *c = f(*c, o);
+    }
+    Self { coords }
+  }
+}
+
+impl<const D: usize> Default for PointN<D> {
+  fn default() -> Self {
+    Self::zero()
+  }
+}
+
+impl<const D: usize> Neg for PointN<D> {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    Self {
+      coords: self.coords.map(|c| -c),
+    }
+  }
+}
+
+impl<const D: usize> Add for PointN<D> {
+  type Output = Self;
+
+  fn add(self, other: Self) -> Self {
+    self.zip_with(other, |a, b| a + b)
+  }
+}
+
+impl<const D: usize> AddAssign for PointN<D> {
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl<const D: usize> Sub for PointN<D> {
+  type Output = Self;
+
+  fn sub(self, other: Self) -> Self {
+    self.zip_with(other, |a, b| a - b)
+  }
+}
+
+impl<const D: usize> SubAssign for PointN<D> {
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl<const D: usize> Mul<i64> for PointN<D> {
+  type Output = Self;
+
+  fn mul(self, other: i64) -> Self {
+    Self {
+      coords: self.coords.map(|c| c * other),
+    }
+  }
+}
+
+impl<const D: usize> MulAssign<i64> for PointN<D> {
+  fn mul_assign(&mut self, other: i64) {
+    *self = *self * other;
+  }
+}
+
+impl From<Point> for PointN<2> {
+  fn from(point: Point) -> Self {
+    Self::new([point.x, point.y])
+  }
+}
+
+impl From<PointN<2>> for Point {
+  fn from(point: PointN<2>) -> Self {
+    let [x, y] = point.coords;
+    Point::new(x, y)
+  }
+}
+
+#[allow(unused)]
+impl<const D: usize> BoundsN<D> {
+  pub const fn new(min: PointN<D>, max: PointN<D>) -> Self {
+    Self { min, max }
+  }
+
+  pub fn check(&self, point: &PointN<D>) -> bool {
+    point.is_in_bounds(self)
+  }
+
+  pub fn from_points<I: IntoIterator<Item = PointN<D>>>(points: I) -> Option<Self> {
+    let mut points = points.into_iter();
+    let first = points.next()?;
+    Some(points.fold(Self::new(first, first), |bounds, p| bounds.include(p)))
+  }
+
+  pub const fn min(&self) -> PointN<D> {
+    self.min
+  }
+
+  pub const fn max(&self) -> PointN<D> {
+    self.max
+  }
+
+  pub fn include(&self, point: PointN<D>) -> Self {
+    Self::new(
+      self.min.zip_with(point, i64::min),
+      self.max.zip_with(point, i64::max),
+    )
+  }
+
+  pub fn expand(&self, margin: i64) -> Self {
+    let margin = PointN::new([margin; D]);
+    Self::new(self.min - margin, self.max + margin)
+  }
+
+  pub fn volume(&self) -> i64 {
+    (0..D)
+      .map(|i| (self.max.coords[i] - self.min.coords[i] + 1).max(0))
+      .product()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn operations() {
+    let a = PointN::new([1, -2, 3, 0]);
+    let b = PointN::new([0, 1, 1, 5]);
+    assert_eq!(a + b, PointN::new([1, -1, 4, 5]));
+    assert_eq!((a - b) * 2, PointN::new([2, -6, 4, -10]));
+    assert_eq!(a.manhattan(b), 11);
+    assert_eq!(a.neighbours().count(), 8);
+    assert_eq!(a.all_neighbours().count(), 80);
+    assert!(a.all_neighbours().all(|n| n != a));
+  }
+
+  #[test]
+  fn bounds() {
+    let bounds = BoundsN::from_points([PointN::new([0, 0]), PointN::new([3, 2])]).unwrap();
+    assert_eq!(bounds.volume(), 12);
+    assert!(bounds.check(&Point::new(3, 2).into()));
+    assert!(!bounds.check(&Point::new(4, 2).into()));
+    assert_eq!(bounds.expand(1).volume(), 30);
+  }
+}
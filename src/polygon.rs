@@ -0,0 +1,130 @@
+use num::integer::gcd;
+
+use crate::point::Point;
+
+//all functions take the polygon vertices in order; the closing edge from the last
+//vertex back to the first is implied, repeating the first vertex at the end is harmless
+
+#[allow(unused)]
+fn edges(vertices: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+  vertices
+    .iter()
+    .zip(vertices.iter().cycle().skip(1))
+    .map(|(&a, &b)| (a, b))
+}
+
+//shoelace formula, positive when the vertices go clockwise on screen (y grows downwards)
+#[allow(unused)]
+pub fn signed_double_area(vertices: &[Point]) -> i128 {
+  edges(vertices)
+    .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+    .sum()
+}
+
+#[allow(unused)]
+pub fn double_area(vertices: &[Point]) -> i128 {
+  signed_double_area(vertices).abs()
+}
+
+#[allow(unused)]
+pub fn area(vertices: &[Point]) -> f64 {
+  double_area(vertices) as f64 / 2.0
+}
+
+//sum of the manhattan lengths of the edges, the actual length when edges are horizontal or vertical
+#[allow(unused)]
+pub fn manhattan_perimeter(vertices: &[Point]) -> i128 {
+  edges(vertices)
+    .map(|(a, b)| (b.x as i128 - a.x as i128).abs() + (b.y as i128 - a.y as i128).abs())
+    .sum()
+}
+
+//lattice points lying on the boundary
+#[allow(unused)]
+pub fn boundary_points(vertices: &[Point]) -> i128 {
+  edges(vertices)
+    .map(|(a, b)| gcd(b.x as i128 - a.x as i128, b.y as i128 - a.y as i128))
+    .sum()
+}
+
+//pick's theorem: A = I + B / 2 - 1
+#[allow(unused)]
+pub fn interior_points(vertices: &[Point]) -> i128 {
+  (double_area(vertices) - boundary_points(vertices) + 2) / 2
+}
+
+//interior plus boundary, i.e. the number of cells covered by a dug out loop
+#[allow(unused)]
+pub fn enclosed_points(vertices: &[Point]) -> i128 {
+  interior_points(vertices) + boundary_points(vertices)
+}
+
+//builds the vertices of a path that starts at the origin and follows direction/length moves
+#[allow(unused)]
+pub fn vertices_from_moves<I: IntoIterator<Item = (Point, i64)>>(moves: I) -> Vec<Point> {
+  let mut current = Point::zero();
+  let mut vertices = vec![current];
+  for (direction, len) in moves {
+    current += direction * len;
+    vertices.push(current);
+  }
+  vertices
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn square() {
+    let square = [
+      Point::new(0, 0),
+      Point::new(4, 0),
+      Point::new(4, 4),
+      Point::new(0, 4),
+    ];
+    assert_eq!(signed_double_area(&square), 32);
+    assert_eq!(area(&square), 16.0);
+    assert_eq!(manhattan_perimeter(&square), 16);
+    assert_eq!(boundary_points(&square), 16);
+    assert_eq!(interior_points(&square), 9);
+    assert_eq!(enclosed_points(&square), 25);
+  }
+
+  #[test]
+  fn dig_plan() {
+    let (r, d, l, u) = (
+      Point::new(1, 0),
+      Point::new(0, 1),
+      Point::new(-1, 0),
+      Point::new(0, -1),
+    );
+    let moves = [
+      (r, 6),
+      (d, 5),
+      (l, 2),
+      (d, 2),
+      (r, 2),
+      (d, 2),
+      (l, 5),
+      (u, 2),
+      (l, 1),
+      (u, 2),
+      (r, 2),
+      (u, 3),
+      (l, 2),
+      (u, 2),
+    ];
+    let vertices = vertices_from_moves(moves);
+    assert_eq!(enclosed_points(&vertices), 62);
+
+    let huge = vertices_from_moves(moves.map(|(p, len)| (p, len * 1_000_000_000)));
+    assert_eq!(double_area(&huge), 84 * 1_000_000_000_000_000_000);
+
+    let corners = [
+      Point::new(i64::MIN, i64::MIN),
+      Point::new(i64::MAX, i64::MAX),
+    ];
+    assert_eq!(manhattan_perimeter(&corners), 8 * (i64::MAX as i128) + 4);
+  }
+}
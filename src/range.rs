@@ -1,3 +1,5 @@
+use std::ops::Range as StdRange;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Range {
   pub start: i64,
@@ -10,8 +12,9 @@ impl Range {
     Self { start, len }
   }
 
+  //lengths above i64::MAX are fine as long as the end itself fits
   pub fn end(&self) -> i64 {
-    self.start + self.len as i64
+    self.checked_end().expect("range end overflow")
   }
 
   pub fn contains(&self, value: i64) -> bool {
@@ -39,6 +42,87 @@ impl Range {
   pub fn take_mut(&mut self, value: u64) {
     self.len -= value;
   }
+
+  //half open [start, end), empty when end <= start
+  pub fn from_bounds(start: i64, end: i64) -> Self {
+    Self {
+      start,
+      len: if end > start { end.abs_diff(start) } else { 0 },
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  pub fn checked_end(&self) -> Option<i64> {
+    self.start.checked_add_unsigned(self.len)
+  }
+
+  pub fn checked_add(&self, value: u64) -> Option<Self> {
+    let len = self.len.checked_add(value)?;
+    let range = Self::new(self.start, len);
+    range.checked_end().map(|_| range)
+  }
+
+  pub fn checked_take(&self, value: u64) -> Option<Self> {
+    Some(Self::new(self.start, self.len.checked_sub(value)?))
+  }
+
+  pub fn shift(&self, offset: i64) -> Self {
+    Self::new(self.start + offset, self.len)
+  }
+
+  pub fn checked_shift(&self, offset: i64) -> Option<Self> {
+    let range = Self::new(self.start.checked_add(offset)?, self.len);
+    range.checked_end().map(|_| range)
+  }
+
+  pub fn overlaps(&self, other: &Self) -> bool {
+    self.intersection(other).is_some()
+  }
+
+  pub fn intersection(&self, other: &Self) -> Option<Self> {
+    let range = Self::from_bounds(self.start.max(other.start), self.end().min(other.end()));
+    if range.is_empty() {
+      None
+    } else {
+      Some(range)
+    }
+  }
+
+  //[start, value) and [value, end), a side is None when empty
+  pub fn split_at(&self, value: i64) -> (Option<Self>, Option<Self>) {
+    let value = value.clamp(self.start, self.end());
+    let left = Self::from_bounds(self.start, value);
+    let right = Self::from_bounds(value, self.end());
+    (
+      (!left.is_empty()).then_some(left),
+      (!right.is_empty()).then_some(right),
+    )
+  }
+
+  //parts of self not covered by other, at most two
+  pub fn subtract(&self, other: &Self) -> Vec<Self> {
+    if !self.overlaps(other) {
+      return if self.is_empty() { vec![] } else { vec![*self] };
+    }
+    let (left, _) = self.split_at(other.start);
+    let (_, right) = self.split_at(other.end());
+    left.into_iter().chain(right).collect()
+  }
+}
+
+impl From<StdRange<i64>> for Range {
+  fn from(range: StdRange<i64>) -> Self {
+    Self::from_bounds(range.start, range.end)
+  }
+}
+
+impl From<Range> for StdRange<i64> {
+  fn from(range: Range) -> Self {
+    range.start..range.end()
+  }
 }
 
 impl Default for Range {
@@ -46,3 +130,47 @@ impl Default for Range {
     Self { start: 0, len: 1 }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn checked() {
+    let range = Range::new(5, 3);
+    assert_eq!(range.checked_take(4), None);
+    assert_eq!(range.checked_take(3), Some(Range::new(5, 0)));
+    assert_eq!(range.checked_add(2), Some(Range::new(5, 5)));
+    assert_eq!(Range::new(i64::MAX - 1, 0).checked_add(2), None);
+    assert_eq!(range.checked_shift(i64::MAX), None);
+    assert_eq!(range.shift(-7), Range::new(-2, 3));
+    assert_eq!(Range::from(2..6), Range::new(2, 4));
+    assert_eq!(Range::from_bounds(6, 2), Range::new(6, 0));
+    let widest = Range::from(i64::MIN..i64::MAX);
+    assert_eq!(widest.len, u64::MAX);
+    assert_eq!(widest.end(), i64::MAX);
+    assert_eq!(StdRange::from(widest), i64::MIN..i64::MAX);
+    assert_eq!(StdRange::from(Range::new(-1, 2)), -1..1);
+  }
+
+  #[test]
+  fn splitting() {
+    let a = Range::from(0..10);
+    let b = Range::from(4..12);
+    assert!(a.overlaps(&b));
+    assert!(!a.overlaps(&Range::from(10..12)));
+    assert_eq!(a.intersection(&b), Some(Range::from(4..10)));
+    assert_eq!(
+      a.split_at(3),
+      (Some(Range::from(0..3)), Some(Range::from(3..10)))
+    );
+    assert_eq!(a.split_at(-5), (None, Some(a)));
+    assert_eq!(a.subtract(&b), vec![Range::from(0..4)]);
+    assert_eq!(
+      a.subtract(&Range::from(2..5)),
+      vec![Range::from(0..2), Range::from(5..10)]
+    );
+    assert_eq!(a.subtract(&Range::from(-1..11)), vec![]);
+    assert_eq!(a.subtract(&Range::from(20..30)), vec![a]);
+  }
+}
@@ -0,0 +1,194 @@
+use crate::range::Range;
+use crate::range_set::RangeSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapEntry {
+  pub source: Range,
+  pub offset: i64,
+}
+
+#[allow(unused)]
+impl MapEntry {
+  pub fn destination(&self) -> Range {
+    self.source.shift(self.offset)
+  }
+}
+
+//piecewise translation: values inside an entry source move by its offset, the rest stay put
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RangeMap {
+  entries: Vec<MapEntry>,
+}
+
+#[allow(unused)]
+impl RangeMap {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn entries(&self) -> &[MapEntry] {
+    &self.entries
+  }
+
+  //false, and nothing inserted, when source overlaps an existing entry
+  pub fn insert(&mut self, source: Range, destination: i64) -> bool {
+    if source.is_empty() {
+      return true;
+    }
+    let i = self
+      .entries
+      .partition_point(|e| e.source.end() <= source.start);
+    if self
+      .entries
+      .get(i)
+      .is_some_and(|e| e.source.overlaps(&source))
+    {
+      return false;
+    }
+    let entry = MapEntry {
+      source,
+      offset: destination - source.start,
+    };
+    self.entries.insert(i, entry);
+    true
+  }
+
+  fn entry_for(&self, value: i64) -> Option<&MapEntry> {
+    let i = self.entries.partition_point(|e| e.source.end() <= value);
+    self.entries.get(i).filter(|e| e.source.contains(value))
+  }
+
+  pub fn map_value(&self, value: i64) -> i64 {
+    value + self.entry_for(value).map_or(0, |e| e.offset)
+  }
+
+  //images of the pieces of range, split wherever an entry starts or ends
+  pub fn map_range(&self, range: Range) -> Vec<Range> {
+    let mut pieces = vec![];
+    let mut rest = range;
+    let first = self
+      .entries
+      .partition_point(|e| e.source.end() <= range.start);
+    for entry in self.entries[first..].iter() {
+      if rest.is_empty() || entry.source.start >= rest.end() {
+        break;
+      }
+      let (before, after) = rest.split_at(entry.source.start);
+      pieces.extend(before);
+      let Some(after) = after else {
+        break;
+      };
+      let (inside, outside) = after.split_at(entry.source.end());
+      pieces.extend(inside.map(|r| r.shift(entry.offset)));
+      rest = outside.unwrap_or(Range::new(rest.end(), 0));
+    }
+    if !rest.is_empty() {
+      pieces.push(rest);
+    }
+    pieces
+  }
+
+  pub fn map_ranges(&self, ranges: &RangeSet) -> RangeSet {
+    ranges.iter().flat_map(|&r| self.map_range(r)).collect()
+  }
+
+  //single map doing the same as applying self and then next
+  pub fn compose(&self, next: &RangeMap) -> RangeMap {
+    let mut breakpoints: Vec<i64> = vec![];
+    for entry in self.entries.iter() {
+      breakpoints.push(entry.source.start);
+      breakpoints.push(entry.source.end());
+    }
+    for boundary in next
+      .entries
+      .iter()
+      .flat_map(|e| [e.source.start, e.source.end()])
+    {
+      if self.entry_for(boundary).is_none() {
+        breakpoints.push(boundary);
+      }
+      for entry in self.entries.iter() {
+        let preimage = boundary - entry.offset;
+        if entry.source.contains(preimage) {
+          breakpoints.push(preimage);
+        }
+      }
+    }
+    breakpoints.sort_unstable();
+    breakpoints.dedup();
+
+    let mut composed = RangeMap::new();
+    let mut pending: Option<MapEntry> = None;
+    for w in breakpoints.windows(2) {
+      let offset = next.map_value(self.map_value(w[0])) - w[0];
+      let segment = Range::from_bounds(w[0], w[1]);
+      pending = match pending {
+        Some(p) if p.offset == offset && p.source.end() == segment.start => Some(MapEntry {
+          source: Range::from_bounds(p.source.start, segment.end()),
+          offset,
+        }),
+        other => {
+          if let Some(p) = other.filter(|p| p.offset != 0) {
+            composed.entries.push(p);
+          }
+          Some(MapEntry {
+            source: segment,
+            offset,
+          })
+        }
+      };
+    }
+    if let Some(p) = pending.filter(|p| p.offset != 0) {
+      composed.entries.push(p);
+    }
+    composed
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  //seed-to-soil and soil-to-fertilizer maps from the 2023 day 5 example
+  fn maps() -> (RangeMap, RangeMap) {
+    let mut soil = RangeMap::new();
+    soil.insert(Range::new(98, 2), 50);
+    soil.insert(Range::new(50, 48), 52);
+    let mut fertilizer = RangeMap::new();
+    fertilizer.insert(Range::new(15, 37), 0);
+    fertilizer.insert(Range::new(52, 2), 37);
+    fertilizer.insert(Range::new(0, 15), 39);
+    (soil, fertilizer)
+  }
+
+  #[test]
+  fn values_and_ranges() {
+    let (mut soil, _) = maps();
+    assert_eq!(soil.map_value(79), 81);
+    assert_eq!(soil.map_value(14), 14);
+    assert_eq!(soil.map_value(99), 51);
+    assert!(!soil.insert(Range::new(40, 20), 0));
+    assert_eq!(
+      soil.map_range(Range::from(45..100)),
+      vec![
+        Range::from(45..50),
+        Range::from(52..100),
+        Range::from(50..52)
+      ]
+    );
+    let ranges: RangeSet = [Range::from(45..100)].into_iter().collect();
+    assert_eq!(soil.map_ranges(&ranges).ranges(), &[Range::from(45..100)]);
+  }
+
+  #[test]
+  fn composition() {
+    let (soil, fertilizer) = maps();
+    let composed = soil.compose(&fertilizer);
+    for seed in -5..110 {
+      assert_eq!(
+        composed.map_value(seed),
+        fertilizer.map_value(soil.map_value(seed))
+      );
+    }
+  }
+}
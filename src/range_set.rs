@@ -0,0 +1,200 @@
+use crate::range::Range;
+
+//sorted, merged and non overlapping ranges; touching ranges are merged too
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RangeSet {
+  ranges: Vec<Range>,
+}
+
+#[allow(unused)]
+impl RangeSet {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn ranges(&self) -> &[Range] {
+    &self.ranges
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &Range> {
+    self.ranges.iter()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.ranges.is_empty()
+  }
+
+  //total number of values covered
+  pub fn len(&self) -> u64 {
+    self.ranges.iter().map(|r| r.len).sum()
+  }
+
+  pub fn first(&self) -> Option<i64> {
+    self.ranges.first().map(|r| r.start)
+  }
+
+  pub fn last(&self) -> Option<i64> {
+    self.ranges.last().map(|r| r.end() - 1)
+  }
+
+  pub fn insert(&mut self, range: Range) {
+    if range.len == 0 {
+      return;
+    }
+    let (start, end) = (range.start, range.end());
+    let lo = self.ranges.partition_point(|r| r.end() < start);
+    let hi = self.ranges.partition_point(|r| r.start <= end);
+    let merged = if lo < hi {
+      Range::from_bounds(
+        start.min(self.ranges[lo].start),
+        end.max(self.ranges[hi - 1].end()),
+      )
+    } else {
+      range
+    };
+    self.ranges.splice(lo..hi, [merged]);
+  }
+
+  pub fn remove(&mut self, range: Range) {
+    if range.len == 0 {
+      return;
+    }
+    let (start, end) = (range.start, range.end());
+    let lo = self.ranges.partition_point(|r| r.end() <= start);
+    let hi = self.ranges.partition_point(|r| r.start < end);
+    if lo >= hi {
+      return;
+    }
+    let mut pieces = vec![];
+    let first = self.ranges[lo];
+    if first.start < start {
+      pieces.push(Range::from_bounds(first.start, start));
+    }
+    let last = self.ranges[hi - 1];
+    if last.end() > end {
+      pieces.push(Range::from_bounds(end, last.end()));
+    }
+    self.ranges.splice(lo..hi, pieces);
+  }
+
+  pub fn contains(&self, value: i64) -> bool {
+    let i = self.ranges.partition_point(|r| r.end() <= value);
+    self.ranges.get(i).is_some_and(|r| r.contains(value))
+  }
+
+  pub fn contains_range(&self, range: &Range) -> bool {
+    if range.len == 0 {
+      return true;
+    }
+    let i = self.ranges.partition_point(|r| r.end() <= range.start);
+    self
+      .ranges
+      .get(i)
+      .is_some_and(|r| r.start <= range.start && r.end() >= range.end())
+  }
+
+  pub fn union(&self, other: &Self) -> Self {
+    let mut result = self.clone();
+    for &range in other.iter() {
+      result.insert(range);
+    }
+    result
+  }
+
+  pub fn difference(&self, other: &Self) -> Self {
+    let mut result = self.clone();
+    for &range in other.iter() {
+      result.remove(range);
+    }
+    result
+  }
+
+  pub fn intersection(&self, other: &Self) -> Self {
+    let mut ranges = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < self.ranges.len() && j < other.ranges.len() {
+      let (a, b) = (self.ranges[i], other.ranges[j]);
+      let start = a.start.max(b.start);
+      let end = a.end().min(b.end());
+      if start < end {
+        ranges.push(Range::from_bounds(start, end));
+      }
+      if a.end() < b.end() {
+        i += 1;
+      } else {
+        j += 1;
+      }
+    }
+    Self { ranges }
+  }
+
+  //holes between the stored ranges, in order
+  pub fn gaps(&self) -> impl Iterator<Item = Range> + '_ {
+    self
+      .ranges
+      .windows(2)
+      .map(|w| Range::from_bounds(w[0].end(), w[1].start))
+  }
+
+  //parts of within not covered by the set
+  pub fn gaps_within(&self, within: Range) -> Self {
+    let mut result = Self::new();
+    result.insert(within);
+    result.difference(self)
+  }
+}
+
+impl FromIterator<Range> for RangeSet {
+  fn from_iter<I: IntoIterator<Item = Range>>(iter: I) -> Self {
+    let mut set = Self::new();
+    for range in iter {
+      set.insert(range);
+    }
+    set
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn set(spans: &[(i64, i64)]) -> RangeSet {
+    spans
+      .iter()
+      .map(|&(s, e)| Range::from_bounds(s, e))
+      .collect()
+  }
+
+  #[test]
+  fn insert_and_remove() {
+    let mut ranges = set(&[(0, 3), (10, 12), (5, 7)]);
+    assert_eq!(ranges, set(&[(0, 3), (5, 7), (10, 12)]));
+    ranges.insert(Range::from_bounds(3, 5));
+    assert_eq!(ranges.ranges(), set(&[(0, 7), (10, 12)]).ranges());
+    ranges.insert(Range::from_bounds(6, 11));
+    assert_eq!(ranges.ranges(), &[Range::from_bounds(0, 12)]);
+    ranges.remove(Range::from_bounds(2, 4));
+    ranges.remove(Range::from_bounds(11, 20));
+    assert_eq!(ranges, set(&[(0, 2), (4, 11)]));
+    assert_eq!(ranges.len(), 9);
+    assert!(ranges.contains(4));
+    assert!(!ranges.contains(3));
+    assert!(ranges.contains_range(&Range::from_bounds(5, 11)));
+    assert!(!ranges.contains_range(&Range::from_bounds(1, 5)));
+  }
+
+  #[test]
+  fn set_operations() {
+    let a = set(&[(0, 5), (10, 15)]);
+    let b = set(&[(3, 12), (14, 20)]);
+    assert_eq!(a.union(&b), set(&[(0, 20)]));
+    assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 12), (14, 15)]));
+    assert_eq!(a.difference(&b), set(&[(0, 3), (12, 14)]));
+    let gaps: Vec<Range> = a.gaps().collect();
+    assert_eq!(gaps, vec![Range::from_bounds(5, 10)]);
+    assert_eq!(
+      a.gaps_within(Range::from_bounds(-2, 12)),
+      set(&[(-2, 0), (5, 10)])
+    );
+  }
+}
@@ -0,0 +1,196 @@
+use crate::grid::Grid;
+use crate::point::{Bounds, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hit {
+  pub point: Point,
+  //steps from the origin to the hit cell
+  pub distance: i64,
+}
+
+#[allow(unused)]
+impl<T> Grid<T> {
+  //cells met when moving from origin (excluded) by direction until leaving the grid
+  pub fn ray(&self, origin: Point, direction: Point) -> impl Iterator<Item = (Point, &T)> {
+    let bounds = self.bounds();
+    std::iter::successors(Some(origin + direction), move |&p| Some(p + direction))
+      .take_while(move |p| bounds.check(p))
+      .map(|p| (p, self.get(&p).unwrap()))
+  }
+
+  //cells in front of the origin until the first one matching stop, which is not yielded
+  pub fn ray_until(
+    &self,
+    origin: Point,
+    direction: Point,
+    stop: impl Fn(&T) -> bool,
+  ) -> impl Iterator<Item = (Point, &T)> {
+    self
+      .ray(origin, direction)
+      .take_while(move |(_, v)| !stop(v))
+  }
+
+  //first cell matching stop, or None when the ray leaves the grid
+  pub fn cast(&self, origin: Point, direction: Point, stop: impl Fn(&T) -> bool) -> Option<Hit> {
+    self
+      .ray(origin, direction)
+      .enumerate()
+      .find(|(_, (_, v))| stop(v))
+      .map(|(i, (point, _))| Hit {
+        point,
+        distance: i as i64 + 1,
+      })
+  }
+}
+
+//precomputed next obstacle from every cell in a few directions, so a cast is a lookup
+#[derive(Debug, Clone)]
+pub struct JumpTable {
+  bounds: Bounds,
+  directions: Vec<Point>,
+  next: Vec<Vec<Option<Point>>>,
+}
+
+#[allow(unused)]
+impl JumpTable {
+  pub fn new<T>(grid: &Grid<T>, directions: &[Point], obstacle: impl Fn(&T) -> bool) -> Self {
+    let bounds = grid.bounds();
+    let width = bounds.width();
+    let index = |p: &Point| (p.y * width + p.x) as usize;
+    let next = directions
+      .iter()
+      .map(|&direction| {
+        //cells further along the direction must be solved first
+        let mut points: Vec<Point> = bounds.iter().collect();
+        points.sort_by_key(|p| -p.dot(direction));
+        let mut table: Vec<Option<Point>> = vec![None; points.len()];
+        for p in points {
+          let ahead = p + direction;
+          table[index(&p)] = match grid.get(&ahead) {
+            Some(v) if obstacle(v) => Some(ahead),
+            Some(_) => table[index(&ahead)],
+            None => None,
+          };
+        }
+        table
+      })
+      .collect();
+    Self {
+      bounds,
+      directions: directions.to_vec(),
+      next,
+    }
+  }
+
+  pub fn orthogonal<T>(grid: &Grid<T>, obstacle: impl Fn(&T) -> bool) -> Self {
+    let directions = [
+      Point::new(0, -1),
+      Point::new(1, 0),
+      Point::new(0, 1),
+      Point::new(-1, 0),
+    ];
+    Self::new(grid, &directions, obstacle)
+  }
+
+  //None when there is no obstacle ahead; panics for directions not in the table
+  pub fn next_obstacle(&self, from: &Point, direction: &Point) -> Option<Point> {
+    let slot = self
+      .directions
+      .iter()
+      .position(|d| d == direction)
+      .expect("direction not precomputed");
+    if !self.bounds.check(from) {
+      return None;
+    }
+    let offset = *from - self.bounds.min();
+    self.next[slot][(offset.y * self.bounds.width() + offset.x) as usize]
+  }
+
+  //same query with one more obstacle that is not in the table, e.g. a candidate wall
+  pub fn next_obstacle_with(
+    &self,
+    from: &Point,
+    direction: &Point,
+    extra: &Point,
+  ) -> Option<Point> {
+    let found = self.next_obstacle(from, direction);
+    let diff = *extra - *from;
+    let steps = diff.dot(*direction) / direction.dot(*direction);
+    let in_front = steps > 0 && *direction * steps == diff;
+    let closer = found.is_none_or(|f| (f - *from).dot(*direction) > diff.dot(*direction));
+    if in_front && closer {
+      Some(*extra)
+    } else {
+      found
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample() -> Grid<char> {
+    Grid::from_lines(&[
+      "....#.....",
+      ".........#",
+      "..........",
+      "..#.......",
+      "#.........",
+    ])
+  }
+
+  #[test]
+  fn casting() {
+    let grid = sample();
+    let origin = Point::new(4, 4);
+    let hit = grid.cast(origin, Point::new(0, -1), |&c| c == '#');
+    assert_eq!(
+      hit,
+      Some(Hit {
+        point: Point::new(4, 0),
+        distance: 4
+      })
+    );
+    assert_eq!(
+      grid
+        .ray_until(origin, Point::new(0, -1), |&c| c == '#')
+        .count(),
+      3
+    );
+    assert_eq!(grid.cast(origin, Point::new(1, 0), |&c| c == '#'), None);
+    assert_eq!(grid.ray(origin, Point::new(-1, -1)).count(), 4);
+  }
+
+  #[test]
+  fn jump_table() {
+    let grid = sample();
+    let table = JumpTable::orthogonal(&grid, |&c| c == '#');
+    let wall = |c: &char| *c == '#';
+    for (p, _) in grid.points() {
+      for d in [
+        Point::new(0, -1),
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(-1, 0),
+      ] {
+        let expected = grid.cast(p, d, wall).map(|h| h.point);
+        assert_eq!(table.next_obstacle(&p, &d), expected);
+      }
+    }
+    let up = Point::new(0, -1);
+    let from = Point::new(4, 4);
+    assert_eq!(
+      table.next_obstacle_with(&from, &up, &Point::new(4, 2)),
+      Some(Point::new(4, 2))
+    );
+    assert_eq!(
+      table.next_obstacle_with(&from, &up, &Point::new(5, 2)),
+      Some(Point::new(4, 0))
+    );
+    assert_eq!(
+      table.next_obstacle_with(&from, &up, &Point::new(4, 6)),
+      Some(Point::new(4, 0))
+    );
+  }
+}
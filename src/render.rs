@@ -0,0 +1,246 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, prelude::*, BufWriter};
+
+use crate::grid::Grid;
+use crate::point::{Path, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+  pub r: u8,
+  pub g: u8,
+  pub b: u8,
+}
+
+#[allow(unused)]
+impl Color {
+  pub const BLACK: Self = Self::new(0, 0, 0);
+  pub const WHITE: Self = Self::new(255, 255, 255);
+  pub const GREY: Self = Self::new(128, 128, 128);
+  pub const RED: Self = Self::new(220, 50, 47);
+  pub const GREEN: Self = Self::new(133, 153, 0);
+  pub const BLUE: Self = Self::new(38, 139, 210);
+  pub const YELLOW: Self = Self::new(181, 137, 0);
+
+  pub const fn new(r: u8, g: u8, b: u8) -> Self {
+    Self { r, g, b }
+  }
+
+  pub fn luma(&self) -> u8 {
+    let luma = 299 * self.r as u32 + 587 * self.g as u32 + 114 * self.b as u32;
+    (luma / 1000) as u8
+  }
+
+  fn ansi_fg(&self) -> String {
+    format!("\x1b[38;2;{};{};{}m", self.r, self.g, self.b)
+  }
+
+  fn ansi_bg(&self) -> String {
+    format!("\x1b[48;2;{};{};{}m", self.r, self.g, self.b)
+  }
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone)]
+struct Overlay {
+  points: HashSet<Point>,
+  glyph: Option<char>,
+  color: Color,
+  background: bool,
+}
+
+//draws a grid with overlays on top; later overlays win over earlier ones
+pub struct Renderer<'a, T> {
+  grid: &'a Grid<T>,
+  glyph: Box<dyn Fn(&T) -> char + 'a>,
+  palette: Box<dyn Fn(&T) -> Color + 'a>,
+  overlays: Vec<Overlay>,
+}
+
+#[allow(unused)]
+impl<'a, T> Renderer<'a, T> {
+  pub fn new(grid: &'a Grid<T>, glyph: impl Fn(&T) -> char + 'a) -> Self {
+    Self {
+      grid,
+      glyph: Box::new(glyph),
+      palette: Box::new(|_| Color::BLACK),
+      overlays: vec![],
+    }
+  }
+
+  //colour of the cells without overlays, used by the image outputs
+  pub fn with_palette(mut self, palette: impl Fn(&T) -> Color + 'a) -> Self {
+    self.palette = Box::new(palette);
+    self
+  }
+
+  //replaces the glyph of every point and paints it with color
+  pub fn overlay<I: IntoIterator<Item = Point>>(
+    mut self,
+    points: I,
+    glyph: char,
+    color: Color,
+  ) -> Self {
+    self.overlays.push(Overlay {
+      points: points.into_iter().collect(),
+      glyph: Some(glyph),
+      color,
+      background: false,
+    });
+    self
+  }
+
+  pub fn path(self, path: &Path, glyph: char, color: Color) -> Self {
+    self.overlay(path.points.iter().cloned(), glyph, color)
+  }
+
+  //keeps the glyph of every point but changes its background
+  pub fn highlight<I: IntoIterator<Item = Point>>(mut self, points: I, color: Color) -> Self {
+    self.overlays.push(Overlay {
+      points: points.into_iter().collect(),
+      glyph: None,
+      color,
+      background: true,
+    });
+    self
+  }
+
+  fn overlays_at(&self, point: &Point) -> impl Iterator<Item = &Overlay> {
+    let point = *point;
+    self
+      .overlays
+      .iter()
+      .filter(move |o| o.points.contains(&point))
+  }
+
+  fn glyph_at(&self, point: &Point, value: &T) -> char {
+    self
+      .overlays_at(point)
+      .filter_map(|o| o.glyph)
+      .last()
+      .unwrap_or_else(|| (self.glyph)(value))
+  }
+
+  fn color_at(&self, point: &Point, value: &T) -> Color {
+    self
+      .overlays_at(point)
+      .last()
+      .map_or_else(|| (self.palette)(value), |o| o.color)
+  }
+
+  pub fn to_text(&self) -> String {
+    let mut text = String::new();
+    for (y, row) in self.grid.rows().enumerate() {
+      for (x, value) in row.iter().enumerate() {
+        text.push(self.glyph_at(&Point::new(x as i64, y as i64), value));
+      }
+      text.push('\n');
+    }
+    text
+  }
+
+  pub fn to_ansi(&self) -> String {
+    let mut text = String::new();
+    for (y, row) in self.grid.rows().enumerate() {
+      for (x, value) in row.iter().enumerate() {
+        let point = Point::new(x as i64, y as i64);
+        let mut styled = false;
+        for overlay in self.overlays_at(&point) {
+          if overlay.background {
+            text.push_str(&overlay.color.ansi_bg());
+          } else {
+            text.push_str(&overlay.color.ansi_fg());
+          }
+          styled = true;
+        }
+        text.push(self.glyph_at(&point, value));
+        if styled {
+          text.push_str(ANSI_RESET);
+        }
+      }
+      text.push('\n');
+    }
+    text
+  }
+
+  fn pixels(&self, scale: usize) -> impl Iterator<Item = Color> + '_ {
+    self.grid.rows().enumerate().flat_map(move |(y, row)| {
+      let colors: Vec<Color> = row
+        .iter()
+        .enumerate()
+        .flat_map(|(x, value)| {
+          let color = self.color_at(&Point::new(x as i64, y as i64), value);
+          std::iter::repeat_n(color, scale)
+        })
+        .collect();
+      std::iter::repeat_n(colors, scale).flatten()
+    })
+  }
+
+  //binary PPM (P6), every cell drawn as a scale x scale square
+  pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+    let (w, h) = (self.grid.width() * scale, self.grid.height() * scale);
+    let mut bytes = format!("P6\n{w} {h}\n255\n").into_bytes();
+    for color in self.pixels(scale) {
+      bytes.extend_from_slice(&[color.r, color.g, color.b]);
+    }
+    bytes
+  }
+
+  //binary PGM (P5) using the luma of each cell colour
+  pub fn to_pgm(&self, scale: usize) -> Vec<u8> {
+    let (w, h) = (self.grid.width() * scale, self.grid.height() * scale);
+    let mut bytes = format!("P5\n{w} {h}\n255\n").into_bytes();
+    bytes.extend(self.pixels(scale).map(|c| c.luma()));
+    bytes
+  }
+
+  pub fn write_ppm(&self, filename: &str, scale: usize) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(filename)?);
+    writer.write_all(&self.to_ppm(scale))
+  }
+
+  pub fn write_pgm(&self, filename: &str, scale: usize) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(filename)?);
+    writer.write_all(&self.to_pgm(scale))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample() -> Grid<char> {
+    Grid::from_lines(&["..#", "...", "#.."])
+  }
+
+  #[test]
+  fn text() {
+    let grid = sample();
+    let renderer = Renderer::new(&grid, |&c| c)
+      .overlay([Point::new(0, 0), Point::new(1, 1)], 'X', Color::RED)
+      .overlay([Point::new(1, 1)], 'O', Color::BLUE)
+      .highlight([Point::new(2, 2)], Color::YELLOW);
+    assert_eq!(renderer.to_text(), "X.#\n.O.\n#..\n");
+    let ansi = renderer.to_ansi();
+    assert!(ansi.starts_with("\x1b[38;2;220;50;47mX\x1b[0m"));
+    assert!(ansi.ends_with("\x1b[48;2;181;137;0m.\x1b[0m\n"));
+  }
+
+  #[test]
+  fn images() {
+    let grid = sample();
+    let renderer = Renderer::new(&grid, |&c| c)
+      .with_palette(|&c| if c == '#' { Color::WHITE } else { Color::BLACK })
+      .highlight([Point::new(0, 0)], Color::RED);
+    let ppm = renderer.to_ppm(2);
+    let header = "P6\n6 6\n255\n";
+    assert!(ppm.starts_with(header.as_bytes()));
+    assert_eq!(ppm.len(), header.len() + 6 * 6 * 3);
+    assert_eq!(ppm[header.len()..header.len() + 3], [220, 50, 47]);
+    let pgm = renderer.to_pgm(1);
+    let header = "P5\n3 3\n255\n";
+    assert_eq!(pgm[header.len()..header.len() + 3], [100, 0, 255]);
+  }
+}
@@ -0,0 +1,166 @@
+use std::ops::Range as StdRange;
+
+use crate::range::Range;
+
+//segment tree over u64 values answering range maximum and "leftmost value >= k" queries
+#[derive(Debug, Clone)]
+pub struct MaxSegmentTree {
+  len: usize,
+  size: usize,
+  tree: Vec<u64>,
+}
+
+#[allow(unused)]
+impl MaxSegmentTree {
+  pub fn new(values: &[u64]) -> Self {
+    let size = values.len().next_power_of_two().max(1);
+    let mut tree = vec![0; 2 * size];
+    tree[size..size + values.len()].copy_from_slice(values);
+    for node in (1..size).rev() {
+      tree[node] = tree[2 * node].max(tree[2 * node + 1]);
+    }
+    Self {
+      len: values.len(),
+      size,
+      tree,
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  pub fn get(&self, index: usize) -> u64 {
+    self.tree[self.size + index]
+  }
+
+  pub fn set(&mut self, index: usize, value: u64) {
+    let mut node = self.size + index;
+    self.tree[node] = value;
+    while node > 1 {
+      node /= 2;
+      self.tree[node] = self.tree[2 * node].max(self.tree[2 * node + 1]);
+    }
+  }
+
+  pub fn max(&self, range: StdRange<usize>) -> u64 {
+    let (mut lo, mut hi) = (range.start + self.size, range.end.min(self.len) + self.size);
+    let mut result = 0;
+    while lo < hi {
+      if lo % 2 == 1 {
+        result = result.max(self.tree[lo]);
+        lo += 1;
+      }
+      if hi % 2 == 1 {
+        hi -= 1;
+        result = result.max(self.tree[hi]);
+      }
+      lo /= 2;
+      hi /= 2;
+    }
+    result
+  }
+
+  //leftmost index inside range holding a value >= min
+  pub fn find_first(&self, range: StdRange<usize>, min: u64) -> Option<usize> {
+    let hi = range.end.min(self.len);
+    self.find_first_in(1, 0, self.size, range.start, hi, min)
+  }
+
+  fn find_first_in(
+    &self,
+    node: usize,
+    node_lo: usize,
+    node_hi: usize,
+    lo: usize,
+    hi: usize,
+    min: u64,
+  ) -> Option<usize> {
+    if node_hi <= lo || node_lo >= hi || self.tree[node] < min {
+      return None;
+    }
+    if node >= self.size {
+      return Some(node - self.size);
+    }
+    let mid = (node_lo + node_hi) / 2;
+    self
+      .find_first_in(2 * node, node_lo, mid, lo, hi, min)
+      .or_else(|| self.find_first_in(2 * node + 1, mid, node_hi, lo, hi, min))
+  }
+}
+
+//free spans sorted by start, e.g. the gaps of a disk map, that can be filled from the front
+#[derive(Debug, Clone)]
+pub struct GapIndex {
+  gaps: Vec<Range>,
+  lengths: MaxSegmentTree,
+}
+
+#[allow(unused)]
+impl GapIndex {
+  pub fn new(mut gaps: Vec<Range>) -> Self {
+    gaps.sort_by_key(|g| g.start);
+    let lengths = MaxSegmentTree::new(&gaps.iter().map(|g| g.len).collect::<Vec<_>>());
+    Self { gaps, lengths }
+  }
+
+  pub fn gaps(&self) -> &[Range] {
+    &self.gaps
+  }
+
+  //leftmost gap able to hold len values that starts before position
+  pub fn find(&self, len: u64, before: i64) -> Option<usize> {
+    let limit = self.gaps.partition_point(|g| g.start < before);
+    self.lengths.find_first(0..limit, len)
+  }
+
+  pub fn get(&self, slot: usize) -> Range {
+    self.gaps[slot]
+  }
+
+  //uses the first len values of the gap and returns them
+  pub fn take(&mut self, slot: usize, len: u64) -> Range {
+    let gap = self.gaps[slot];
+    let (taken, rest) = gap.split_at(gap.start + len as i64);
+    let rest = rest.unwrap_or(Range::new(gap.end(), 0));
+    self.gaps[slot] = rest;
+    self.lengths.set(slot, rest.len);
+    taken.unwrap_or(Range::new(gap.start, 0))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn max_tree() {
+    let mut tree = MaxSegmentTree::new(&[3, 1, 4, 1, 5, 9, 2]);
+    assert_eq!(tree.max(0..7), 9);
+    assert_eq!(tree.max(1..4), 4);
+    assert_eq!(tree.find_first(0..7, 4), Some(2));
+    assert_eq!(tree.find_first(3..7, 4), Some(4));
+    assert_eq!(tree.find_first(0..4, 5), None);
+    tree.set(2, 0);
+    assert_eq!(tree.find_first(0..7, 4), Some(4));
+    for k in 0..10 {
+      let expected = (0..7).find(|&i| tree.get(i) >= k);
+      assert_eq!(tree.find_first(0..7, k), expected);
+    }
+  }
+
+  #[test]
+  fn gaps() {
+    let mut index = GapIndex::new(vec![Range::new(10, 3), Range::new(2, 3), Range::new(20, 5)]);
+    assert_eq!(index.find(3, 100), Some(0));
+    assert_eq!(index.find(4, 100), Some(2));
+    assert_eq!(index.find(4, 20), None);
+    assert_eq!(index.take(0, 2), Range::new(2, 2));
+    assert_eq!(index.get(0), Range::new(4, 1));
+    assert_eq!(index.find(2, 100), Some(1));
+  }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use crate::grid::Grid;
+use crate::point::{Bounds, Point};
+use crate::render::Renderer;
+
+//grid without fixed size; cells that were never set read as the default value
+#[allow(unused)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+  cells: HashMap<Point, T>,
+  default: T,
+  bounds: Option<Bounds>,
+}
+
+#[allow(unused)]
+impl<T> SparseGrid<T> {
+  pub fn new(default: T) -> Self {
+    Self {
+      cells: HashMap::new(),
+      default,
+      bounds: None,
+    }
+  }
+
+  //smallest bounds containing every set cell
+  pub fn bounds(&self) -> Option<Bounds> {
+    self.bounds
+  }
+
+  pub fn len(&self) -> usize {
+    self.cells.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.cells.is_empty()
+  }
+
+  pub fn contains(&self, point: &Point) -> bool {
+    self.cells.contains_key(point)
+  }
+
+  pub fn get(&self, point: &Point) -> &T {
+    self.cells.get(point).unwrap_or(&self.default)
+  }
+
+  pub fn get_set(&self, point: &Point) -> Option<&T> {
+    self.cells.get(point)
+  }
+
+  pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+    self.bounds = Some(match self.bounds {
+      Some(bounds) => bounds.include(point),
+      None => Bounds::new(point, point),
+    });
+    self.cells.insert(point, value)
+  }
+
+  pub fn remove(&mut self, point: &Point) -> Option<T> {
+    let removed = self.cells.remove(point)?;
+    if let Some(bounds) = self.bounds {
+      let min = bounds.min();
+      let max = bounds.max();
+      //only a cell on the edge can shrink the bounds
+      if point.x == min.x || point.x == max.x || point.y == min.y || point.y == max.y {
+        self.bounds = Bounds::from_points(self.cells.keys().cloned());
+      }
+    }
+    Some(removed)
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (&Point, &T)> {
+    self.cells.iter()
+  }
+
+  pub fn points(&self) -> impl Iterator<Item = &Point> {
+    self.cells.keys()
+  }
+}
+
+#[allow(unused)]
+impl<T: Clone> SparseGrid<T> {
+  pub fn get_or_insert_default(&mut self, point: Point) -> &mut T {
+    if !self.contains(&point) {
+      self.insert(point, self.default.clone());
+    }
+    self.cells.get_mut(&point).unwrap()
+  }
+
+  //dense copy of the bounding box, the top left cell of the grid is bounds.min()
+  pub fn to_grid(&self) -> Grid<T> {
+    let Some(bounds) = self.bounds else {
+      return Grid::new(vec![]);
+    };
+    let mut rows =
+      vec![vec![self.default.clone(); bounds.width() as usize]; bounds.height() as usize];
+    for (point, value) in self.cells.iter() {
+      let offset = *point - bounds.min();
+      rows[offset.y as usize][offset.x as usize] = value.clone();
+    }
+    Grid::new(rows)
+  }
+
+  pub fn render(&self, glyph: impl Fn(&T) -> char) -> String {
+    let grid = self.to_grid();
+    let text = Renderer::new(&grid, glyph).to_text();
+    text
+  }
+}
+
+#[allow(unused)]
+impl SparseGrid<char> {
+  //keeps every character but the background one, x is the column and y the line
+  pub fn parse<S: AsRef<str>>(lines: &[S], background: char) -> Self {
+    let mut grid = Self::new(background);
+    for (y, line) in lines.iter().enumerate() {
+      for (x, c) in line.as_ref().chars().enumerate() {
+        if c != background {
+          grid.insert(Point::new(x as i64, y as i64), c);
+        }
+      }
+    }
+    grid
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn dynamic_bounds() {
+    let mut grid = SparseGrid::new(0);
+    assert_eq!(grid.bounds(), None);
+    grid.insert(Point::new(2, 3), 1);
+    grid.insert(Point::new(-4, 1), 2);
+    *grid.get_or_insert_default(Point::new(0, 10)) += 5;
+    assert_eq!(
+      grid.bounds(),
+      Some(Bounds::new(Point::new(-4, 1), Point::new(2, 10)))
+    );
+    assert_eq!(*grid.get(&Point::new(0, 10)), 5);
+    assert_eq!(*grid.get(&Point::new(100, 100)), 0);
+    grid.remove(&Point::new(0, 10));
+    assert_eq!(
+      grid.bounds(),
+      Some(Bounds::new(Point::new(-4, 1), Point::new(2, 3)))
+    );
+  }
+
+  #[test]
+  fn parse_and_render() {
+    let grid = SparseGrid::parse(&["....", ".a..", "...b"], '.');
+    assert_eq!(grid.len(), 2);
+    assert_eq!(*grid.get(&Point::new(3, 2)), 'b');
+    assert_eq!(grid.render(|&c| c), "a..\n..b\n");
+  }
+}